@@ -3,10 +3,7 @@ use crate::just_asc::Draw;
 use std::f64::consts::PI;
 
 fn borders(grid: &mut just_asc::Grid) {
-    grid.line(0., 0., 100., 0.);
-    grid.line(100., 0., 100., 100.);
-    grid.line(100., 100., 0., 100.);
-    grid.line(0., 0., 0., 100.);
+    grid.rect(0., 0., 100., 100.);
 }
 
 fn spinning_triangle(grid: &mut just_asc::Grid, cycle: f64, height: f64) {
@@ -14,16 +11,11 @@ fn spinning_triangle(grid: &mut just_asc::Grid, cycle: f64, height: f64) {
     let mut transform = grid.transform();
     transform.translate(50., 50.).rotate(cycle);
 
-    let x1 = 0.;
-    let y1 = -2. * height / 3.;
-    let x2 = side_length / 2.;
-    let y2 = height / 3.;
-    let x3 = -side_length / 2.;
-    let y3 = height / 3.;
-
-    transform.line(x1, y1, x2, y2);
-    transform.line(x2, y2, x3, y3);
-    transform.line(x3, y3, x1, y1);
+    transform.polygon(&[
+        (0., -2. * height / 3.),
+        (side_length / 2., height / 3.),
+        (-side_length / 2., height / 3.),
+    ]);
 }
 
 fn triangle_stuff(grid: &mut just_asc::Grid, frame: usize) {
@@ -67,17 +59,22 @@ fn ellipses(grid: &mut just_asc::Grid, frame: usize) {
 
 fn main() {
     let config = just_asc::GridConfig {
-        cell_width: 72,
-        cell_height: 36,
+        fit_terminal: true,
+        aspect: just_asc::Aspect::Fit,
         tileset: just_asc::tilesets::BRAILLE,
         max_framerate: Some(50),
         print_timing: true,
+        ..just_asc::DEFAULT_CONFIG
     };
 
-    just_asc::draw(config, |grid: &mut just_asc::Grid, frame: usize| {
-        borders(grid);
-        triangle_stuff(grid, frame);
-        circle_stuff(grid, frame);
-        ellipses(grid, frame);
-    });
+    just_asc::draw(
+        config,
+        |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+            let frame = frame.number;
+            borders(grid);
+            triangle_stuff(grid, frame);
+            circle_stuff(grid, frame);
+            ellipses(grid, frame);
+        },
+    );
 }
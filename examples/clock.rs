@@ -3,20 +3,27 @@ use crate::just_asc::Draw;
 use std::f64::consts::PI;
 
 fn main() {
+    let config = just_asc::GridConfig {
+        fit_terminal: true,
+        aspect: just_asc::Aspect::Fit,
+        ..just_asc::DEFAULT_CONFIG
+    };
+
     just_asc::draw(
-        just_asc::DEFAULT_CONFIG,
-        |grid: &mut just_asc::Grid, frame: usize| {
+        config,
+        |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+            let seconds = frame.elapsed.as_secs_f64();
             grid.circle(50., 50., 50.);
 
             grid.with_transform(|mut transform| {
                 transform.translate(50., 50.);
-                transform.rotate(PI / 20. * frame as f64);
+                transform.rotate(PI * seconds);
                 transform.line(0., 10., 0., -40.);
             });
 
             grid.with_transform(|mut transform| {
                 transform.translate(50., 50.);
-                transform.rotate(PI / 240. * frame as f64);
+                transform.rotate(PI / 12. * seconds);
                 transform.line(0., 5., 0., -20.);
             });
         },
@@ -1,8 +1,8 @@
 extern crate just_asc;
-use crate::just_asc::Draw;
+use crate::just_asc::{Control, Draw, Key};
 use std::f64::consts::PI;
 
-fn sliding_angles(grid: &mut just_asc::Grid, frame: usize) {
+fn sliding_angles(grid: &mut just_asc::Transform, frame: usize) {
     let angle = (2. * PI / 150.) * frame as f64;
     let mut transform = grid.transform();
     transform.translate(angle.cos() * 4., 0.);
@@ -26,7 +26,7 @@ fn sliding_angles(grid: &mut just_asc::Grid, frame: usize) {
     transform.line(85., 5., 98.5, 8.);
 }
 
-fn spinning_lines(grid: &mut just_asc::Grid, frame: usize) {
+fn spinning_lines(grid: &mut just_asc::Transform, frame: usize) {
     let angle = (2. * PI / 180.) * frame as f64;
     let x = angle.cos();
     let y = angle.sin();
@@ -51,7 +51,7 @@ fn spinning_lines(grid: &mut just_asc::Grid, frame: usize) {
     );
 }
 
-fn circle_stuff(grid: &mut just_asc::Grid, frame: usize) {
+fn circle_stuff(grid: &mut just_asc::Transform, frame: usize) {
     let slow = (2. * PI / 120.) * frame as f64;
     let x = slow.cos();
     let y = slow.sin();
@@ -61,7 +61,7 @@ fn circle_stuff(grid: &mut just_asc::Grid, frame: usize) {
     grid.circle(50. + (x * 5.), 85., 10. + (y * 10.));
 }
 
-fn ellipses(grid: &mut just_asc::Grid, frame: usize) {
+fn ellipses(grid: &mut just_asc::Transform, frame: usize) {
     let slow = (2. * PI / 240.) * frame as f64;
     let x = slow.cos();
 
@@ -77,17 +77,41 @@ fn ellipses(grid: &mut just_asc::Grid, frame: usize) {
 
 fn main() {
     let config = just_asc::GridConfig {
-        cell_width: 96,
-        cell_height: 48,
-        tileset: just_asc::tilesets::PURE_ASCII,
+        fit_terminal: true,
+        aspect: just_asc::Aspect::Fit,
+        keyboard: true,
         max_framerate: Some(60),
-        print_timing: false,
+        ..just_asc::DEFAULT_CONFIG
     };
 
-    just_asc::draw(config, |grid: &mut just_asc::Grid, frame: usize| {
-        sliding_angles(grid, frame);
-        spinning_lines(grid, frame);
-        circle_stuff(grid, frame);
-        ellipses(grid, frame);
+    // space pauses, the arrow keys turn everything around, and q quits
+    let mut time = 0;
+    let mut paused = false;
+    let mut angle = 0.;
+    just_asc::draw(config, |grid: &mut just_asc::Grid, _: just_asc::Frame| {
+        for key in grid.keys() {
+            match key {
+                Key::Char(' ') => paused = !paused,
+                Key::Left => angle -= PI / 16.,
+                Key::Right => angle += PI / 16.,
+                Key::Char('q') | Key::Escape => return Control::Stop,
+                _ => {}
+            }
+        }
+
+        let mut transform = grid.transform();
+        transform
+            .translate(50., 50.)
+            .rotate(angle)
+            .apply(just_asc::Matrix::translation(-50., -50.));
+        sliding_angles(&mut transform, time);
+        spinning_lines(&mut transform, time);
+        circle_stuff(&mut transform, time);
+        ellipses(&mut transform, time);
+
+        if !paused {
+            time += 1;
+        }
+        Control::Continue
     });
 }
@@ -0,0 +1,33 @@
+extern crate just_asc;
+use crate::just_asc::Draw;
+use std::f64::consts::PI;
+use std::time::Duration;
+
+fn main() {
+    let config = just_asc::GridConfig {
+        cell_width: 16,
+        cell_height: 8,
+        aspect: just_asc::Aspect::Fit,
+        max_framerate: Some(30),
+        ..just_asc::DEFAULT_CONFIG
+    };
+
+    println!("reticulating splines...");
+    let spinner = just_asc::Spinner::start(
+        config,
+        |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+            let seconds = frame.elapsed.as_secs_f64();
+            grid.with_transform(|mut transform| {
+                transform.translate(50., 50.).rotate(PI * seconds);
+                transform.arc(0., 0., 40., 0., PI);
+                transform.rotate(-2. * PI * seconds);
+                transform.arc(0., 0., 20., 0., PI);
+            });
+        },
+    );
+
+    // pretend to be busy
+    std::thread::sleep(Duration::from_secs(3));
+
+    spinner.finish_with("splines reticulated.");
+}
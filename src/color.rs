@@ -0,0 +1,63 @@
+//! Colors for your drawings.
+
+use std::fmt;
+
+/// A color to draw with, using the terminal's ANSI color escapes. Pick one with
+/// [`Draw::set_color`](crate::Draw::set_color).
+///
+/// Not every terminal supports every kind of color: the 16 [`Ansi`](Color::Ansi) colors work
+/// just about everywhere, [`Fixed`](Color::Fixed) colors almost everywhere, and
+/// [`Rgb`](Color::Rgb) colors in most modern terminals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+    /// Whatever color the terminal normally draws text in.
+    #[default]
+    Default,
+
+    /// One of the 16 standard terminal colors: `0`-`7` are black, red, green, yellow, blue,
+    /// magenta, cyan and white, and `8`-`15` are their bright versions. (The exact shades are up
+    /// to the terminal's theme.)
+    ///
+    /// There are only 16 of these: anything from `16` up is the same as the [`Fixed`](Color::Fixed)
+    /// color with that number.
+    Ansi(u8),
+
+    /// One of the terminal's 256 extended colors: the 16 standard colors, a 6x6x6 color cube, and
+    /// a ramp of grays.
+    Fixed(u8),
+
+    /// A 24-bit "truecolor" color: red, green and blue.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Standard black.
+    pub const BLACK: Color = Color::Ansi(0);
+    /// Standard red.
+    pub const RED: Color = Color::Ansi(1);
+    /// Standard green.
+    pub const GREEN: Color = Color::Ansi(2);
+    /// Standard yellow.
+    pub const YELLOW: Color = Color::Ansi(3);
+    /// Standard blue.
+    pub const BLUE: Color = Color::Ansi(4);
+    /// Standard magenta.
+    pub const MAGENTA: Color = Color::Ansi(5);
+    /// Standard cyan.
+    pub const CYAN: Color = Color::Ansi(6);
+    /// Standard white.
+    pub const WHITE: Color = Color::Ansi(7);
+}
+
+// writes the SGR escape sequence that switches the terminal's text to this color
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Color::Default => write!(f, "\x1b[39m"),
+            Color::Ansi(n) if n < 8 => write!(f, "\x1b[{}m", 30 + n),
+            Color::Ansi(n) if n < 16 => write!(f, "\x1b[{}m", 90 + n - 8),
+            Color::Ansi(n) | Color::Fixed(n) => write!(f, "\x1b[38;5;{}m", n),
+            Color::Rgb(r, g, b) => write!(f, "\x1b[38;2;{};{};{}m", r, g, b),
+        }
+    }
+}
@@ -0,0 +1,169 @@
+//! Keys pressed on the keyboard.
+
+/// A key someone pressed. [`draw`](crate::draw) hands you these in
+/// [`Grid::keys`](crate::Grid::keys) when [`GridConfig::keyboard`](crate::GridConfig::keyboard)
+/// is on.
+///
+/// Terminals only send some keys along: modifiers on their own (like Shift) never show up, and
+/// Shift or Alt with another key mostly just changes which key it looks like.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A letter, number, symbol or space: anything that types something.
+    Char(char),
+
+    /// Control and a letter, like `Ctrl('d')`. (Ctrl-C and Ctrl-Z don't come through here: they
+    /// still stop the program, same as always.)
+    Ctrl(char),
+
+    /// Enter (or Return).
+    Enter,
+    /// Tab.
+    Tab,
+    /// Backspace.
+    Backspace,
+    /// Escape.
+    Escape,
+
+    /// The up arrow.
+    Up,
+    /// The down arrow.
+    Down,
+    /// The left arrow.
+    Left,
+    /// The right arrow.
+    Right,
+
+    /// Home.
+    Home,
+    /// End.
+    End,
+    /// Page Up.
+    PageUp,
+    /// Page Down.
+    PageDown,
+    /// Insert.
+    Insert,
+    /// Delete (the one that deletes forwards).
+    Delete,
+
+    /// A function key, from `F(1)` to `F(12)`.
+    F(u8),
+}
+
+impl Key {
+    /// Reads keys out of whatever a terminal sent: plain text, control characters, and the escape
+    /// sequences terminals use for arrows and the like. Anything it doesn't recognize is skipped.
+    ///
+    /// You won't need this for [`draw`](crate::draw), which does it for you, but it comes in handy
+    /// if the keys are coming from somewhere else (say, a socket).
+    ///
+    /// ```
+    /// use just_asc::Key;
+    ///
+    /// assert_eq!(
+    ///     Key::parse(b"hi\x1b[A\x1b[D\r\x04"),
+    ///     vec![
+    ///         Key::Char('h'),
+    ///         Key::Char('i'),
+    ///         Key::Up,
+    ///         Key::Left,
+    ///         Key::Enter,
+    ///         Key::Ctrl('d'),
+    ///     ]
+    /// );
+    /// ```
+    pub fn parse(bytes: &[u8]) -> Vec<Key> {
+        let mut keys = Vec::new();
+        let mut rest = bytes;
+        while let Some(&byte) = rest.first() {
+            let (key, length) = match byte {
+                0x1b => escape(rest),
+                b'\r' | b'\n' => (Some(Key::Enter), 1),
+                b'\t' => (Some(Key::Tab), 1),
+                0x7f | 0x08 => (Some(Key::Backspace), 1),
+                0x01..=0x1a => (Some(Key::Ctrl((b'a' + byte - 1) as char)), 1),
+                0x00..=0x1f => (None, 1),
+                _ => text(rest),
+            };
+            keys.extend(key);
+            rest = &rest[length..];
+        }
+        keys
+    }
+}
+
+// an escape sequence (or just Escape on its own), and how many bytes it took up
+fn escape(bytes: &[u8]) -> (Option<Key>, usize) {
+    match bytes.get(1) {
+        // `ESC O` and a letter: arrows, Home and End in "application" mode, and F1-F4
+        Some(b'O') => match bytes.get(2) {
+            Some(&letter) => (letter_key(letter), 3),
+            None => (Some(Key::Escape), 1),
+        },
+        // `ESC [`, some numbers (separated by `;`), and a letter or `~` to finish it off
+        Some(b'[') => {
+            let end = bytes[2..]
+                .iter()
+                .position(|byte| !(byte.is_ascii_digit() || *byte == b';'));
+            let end = match end {
+                Some(end) => end + 2,
+                None => return (Some(Key::Escape), 1),
+            };
+            // the first number says which key it is, and any after it are the modifiers
+            let number = std::str::from_utf8(&bytes[2..end])
+                .ok()
+                .and_then(|numbers| numbers.split(';').next())
+                .and_then(|number| number.parse::<u8>().ok());
+            let key = match bytes[end] {
+                b'~' => number.and_then(tilde_key),
+                letter => letter_key(letter),
+            };
+            (key, end + 1)
+        }
+        _ => (Some(Key::Escape), 1),
+    }
+}
+
+fn letter_key(letter: u8) -> Option<Key> {
+    match letter {
+        b'A' => Some(Key::Up),
+        b'B' => Some(Key::Down),
+        b'C' => Some(Key::Right),
+        b'D' => Some(Key::Left),
+        b'H' => Some(Key::Home),
+        b'F' => Some(Key::End),
+        b'P'..=b'S' => Some(Key::F(letter - b'P' + 1)),
+        _ => None,
+    }
+}
+
+fn tilde_key(number: u8) -> Option<Key> {
+    match number {
+        1 | 7 => Some(Key::Home),
+        2 => Some(Key::Insert),
+        3 => Some(Key::Delete),
+        4 | 8 => Some(Key::End),
+        5 => Some(Key::PageUp),
+        6 => Some(Key::PageDown),
+        11..=15 => Some(Key::F(number - 10)),
+        17..=21 => Some(Key::F(number - 11)),
+        23 | 24 => Some(Key::F(number - 12)),
+        _ => None,
+    }
+}
+
+// one character of (UTF-8) text, and how many bytes it took up
+fn text(bytes: &[u8]) -> (Option<Key>, usize) {
+    let length = match bytes[0] {
+        0xf0..=0xff => 4,
+        0xe0..=0xef => 3,
+        0xc0..=0xdf => 2,
+        _ => 1,
+    };
+    let length = length.min(bytes.len());
+    let key = std::str::from_utf8(&bytes[..length])
+        .ok()
+        .and_then(|text| text.chars().next())
+        .map(Key::Char);
+    (key, length)
+}
@@ -2,7 +2,7 @@
 //! Have some fun, draw in your terminal! Or someone else's.
 //!
 //! This library provides a "canvas" for you to draw in, rendered in ASCII. Uses might include
-//! loading animations in CLI tools, or dorky projector backdrops at your party.
+//! loading animations in CLI tools (see [`Spinner`]), or dorky projector backdrops at your party.
 //!
 //! ```
 //! use std::f64::consts::PI;
@@ -11,21 +11,22 @@
 //! fn main() {
 //!     just_asc::draw(
 //!         just_asc::DEFAULT_CONFIG,
-//!         |grid: &mut just_asc::Grid, frame: usize| {
+//!         |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+//!             let seconds = frame.elapsed.as_secs_f64();
 //!             grid.circle(50., 50., 50.);
 //!
 //!             grid.with_transform(|mut transform| {
 //!                 transform.translate(50., 50.);
-//!                 transform.rotate(PI / 20. * frame as f64);
+//!                 transform.rotate(PI * seconds);
 //!                 transform.line(0., 10., 0., -40.);
 //!             });
 //!
 //!             grid.with_transform(|mut transform| {
 //!                 transform.translate(50., 50.);
-//!                 transform.rotate(PI / 240. * frame as f64);
+//!                 transform.rotate(PI / 12. * seconds);
 //!                 transform.line(0., 5., 0., -20.);
 //!             });
-//!             # if frame > 30 {
+//!             # if frame.number > 30 {
 //!             #   std::process::exit(0)
 //!             # }
 //!         },
@@ -33,84 +34,100 @@
 //! }
 //! ```
 
+mod color;
+mod keys;
+mod matrix;
 mod shapes;
+mod spinner;
+pub mod terminal;
 pub mod tilesets;
 
-use crate::shapes::{Circle, Ellipse, Line, Point, Rectangle};
+pub use crate::color::Color;
+pub use crate::keys::Key;
+pub use crate::matrix::Matrix;
+pub use crate::spinner::Spinner;
+pub use crate::terminal::TerminalGuard;
+pub use crate::tilesets::Tileset;
+use std::fmt;
+use std::io::{self, Write};
+use std::ops::Range;
+use std::time::{Duration, Instant};
+
+use crate::shapes::{Arc, Bezier, Circle, Ellipse, Line, Point, Polygon, Rectangle};
+use crate::terminal::Keyboard;
 
 #[derive(Debug)]
 struct Cell {
     coords: Rectangle,
-    quadrants: [Rectangle; 4],
-    quads_filled: [bool; 4],
+    // the pieces the cell is split into, listed left-to-right, top-to-bottom
+    regions: Vec<Rectangle>,
+    // one bit per region, with the first region as the highest bit
+    filled: u8,
+    // the color of the last shape to fill any of the cell's regions
+    color: Color,
 }
 
 impl Cell {
-    fn new(p1: Point, p2: Point) -> Self {
-        let mid_x = (p1.x + p2.x) / 2.;
-        let mid_y = (p1.y + p2.y) / 2.;
-
-        let quadrants = [
-            Rectangle::new(p1.clone(), Point::new(mid_x, mid_y)),
-            Rectangle::new(Point::new(mid_x, p1.y), Point::new(p2.x, mid_y)),
-            Rectangle::new(Point::new(p1.x, mid_y), Point::new(mid_x, p2.y)),
-            Rectangle::new(Point::new(mid_x, mid_y), p2.clone()),
-        ];
+    fn new(p1: Point, p2: Point, columns: usize, rows: usize) -> Self {
         Cell {
+            regions: Cell::regions(&p1, &p2, columns, rows).collect(),
             coords: Rectangle::new(p1, p2),
-            quadrants,
-            quads_filled: [false, false, false, false],
+            filled: 0,
+            color: Color::Default,
         }
     }
 
-    fn render_line(&mut self, line: &Line) {
-        if !self.coords.overlaps_line(line) {
-            return;
-        }
-        self.quads_filled[0] = self.quads_filled[0] || self.quadrants[0].overlaps_line(line);
-        self.quads_filled[1] = self.quads_filled[1] || self.quadrants[1].overlaps_line(line);
-        self.quads_filled[2] = self.quads_filled[2] || self.quadrants[2].overlaps_line(line);
-        self.quads_filled[3] = self.quads_filled[3] || self.quadrants[3].overlaps_line(line);
+    // moves the cell (and its regions) to cover a different part of the world, leaving it blank
+    fn place(&mut self, p1: Point, p2: Point, columns: usize, rows: usize) {
+        self.regions.clear();
+        self.regions.extend(Cell::regions(&p1, &p2, columns, rows));
+        self.coords = Rectangle::new(p1, p2);
+        self.filled = 0;
+        self.color = Color::Default;
     }
 
-    fn render_circle(&mut self, circle: &Circle) {
-        if !self.coords.overlaps_circle(circle) {
-            return;
-        }
-        self.quads_filled[0] = self.quads_filled[0] || self.quadrants[0].overlaps_circle(circle);
-        self.quads_filled[1] = self.quads_filled[1] || self.quadrants[1].overlaps_circle(circle);
-        self.quads_filled[2] = self.quads_filled[2] || self.quadrants[2].overlaps_circle(circle);
-        self.quads_filled[3] = self.quads_filled[3] || self.quadrants[3].overlaps_circle(circle);
-    }
+    // splits the rectangle between two corners into `columns` x `rows` regions
+    fn regions(
+        p1: &Point,
+        p2: &Point,
+        columns: usize,
+        rows: usize,
+    ) -> impl Iterator<Item = Rectangle> {
+        let width = (p2.x - p1.x) / columns as f64;
+        let height = (p2.y - p1.y) / rows as f64;
+        let (left, top) = (p1.x, p1.y);
 
-    fn render_ellipse(&mut self, ellipse: &Ellipse) {
-        if !self.coords.overlaps_ellipse(ellipse) {
-            return;
-        }
-        self.quads_filled[0] = self.quads_filled[0] || self.quadrants[0].overlaps_ellipse(ellipse);
-        self.quads_filled[1] = self.quads_filled[1] || self.quadrants[1].overlaps_ellipse(ellipse);
-        self.quads_filled[2] = self.quads_filled[2] || self.quadrants[2].overlaps_ellipse(ellipse);
-        self.quads_filled[3] = self.quads_filled[3] || self.quadrants[3].overlaps_ellipse(ellipse);
+        (0..rows).flat_map(move |j| {
+            (0..columns).map(move |i| {
+                let x = left + i as f64 * width;
+                let y = top + j as f64 * height;
+                Rectangle::new(Point::new(x, y), Point::new(x + width, y + height))
+            })
+        })
     }
 
-    fn print(&self, tileset: &[char; 16]) -> char {
-        let q = self.quads_filled;
-
-        let mut i = 0;
-        if q[3] {
-            i += 1;
-        }
-        if q[2] {
-            i += 2;
-        }
-        if q[1] {
-            i += 4;
+    // fills in every region a shape touches, according to its `overlaps` check. Returns whether
+    // the shape touched the cell at all.
+    fn render<F>(&mut self, overlaps: F) -> bool
+    where
+        F: Fn(&Rectangle) -> bool,
+    {
+        if !overlaps(&self.coords) {
+            return false;
         }
-        if q[0] {
-            i += 8;
+        let count = self.regions.len();
+        let mut touched = false;
+        for (k, region) in self.regions.iter().enumerate() {
+            if overlaps(region) {
+                self.filled |= 1 << (count - 1 - k);
+                touched = true;
+            }
         }
+        touched
+    }
 
-        tileset[i]
+    fn print(&self, tileset: &Tileset) -> char {
+        tileset.glyph(self.filled)
     }
 }
 
@@ -129,32 +146,257 @@ pub struct GridConfig {
     /// The height of your canvas (# of characters).
     pub cell_height: usize,
 
-    /// A list of characters to use in your drawing, one for each combination of filled quadrants
-    /// in a cell.
+    /// Size the canvas to fill the terminal (less a line at the bottom for the cursor), and keep
+    /// it that way when the terminal's resized. Your drawing doesn't need to change: the
+    /// [`viewport`](GridConfig::viewport) stays the same, just spread over more (or fewer)
+    /// characters.
+    ///
+    /// If there's no terminal to measure (say, you're printing to a file), `cell_width` and
+    /// `cell_height` are used instead. Defaults to `false`.
+    pub fit_terminal: bool,
+
+    /// Draw right where the cursor is, in amongst the rest of your program's output, instead of
+    /// taking over the whole screen. [`draw`] sets aside `cell_height` lines below the cursor
+    /// and only ever redraws those, and when the animation [stops](Control::Stop), the last frame
+    /// stays put (and scrolls off into your scrollback like anything else would).
+    ///
+    /// With [`fit_terminal`](GridConfig::fit_terminal), an inline grid is as wide as the terminal,
+    /// but still only `cell_height` tall (or as tall as will fit). Defaults to `false`.
+    pub inline: bool,
+
+    /// Read the keyboard while [`draw`] is running, and pass along the keys that were pressed in
+    /// each [`Frame`] (see [`Grid::keys`]). Typing stops showing up on the screen, too. If stdin
+    /// isn't a terminal, there just aren't any keys. Defaults to `false`.
+    pub keyboard: bool,
+
+    /// The part of the world the grid shows, in whatever units your drawing uses. Defaults to
+    /// 0-100 both ways, with (0,0) in the top-left corner. See [`Viewport`].
+    pub viewport: Viewport,
+
+    /// How the viewport maps onto the grid when their shapes don't match. Defaults to
+    /// [`Aspect::Stretch`].
+    pub aspect: Aspect,
+
+    /// How many times taller than it is wide one character is in your terminal, which
+    /// [`Aspect::Fit`] and [`Aspect::Fill`] need to know to keep things square. Most terminal
+    /// fonts are around `2.0`, the default.
+    ///
+    /// A character can't be flat (or infinitely tall), so anything that isn't a number more than
+    /// `0` gets you the default instead:
+    ///
+    /// ```
+    /// use just_asc::{Aspect, Draw};
+    ///
+    /// let circle = |cell_aspect: f64| {
+    ///     let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///         cell_width: 20,
+    ///         cell_height: 5,
+    ///         aspect: Aspect::Fit,
+    ///         cell_aspect,
+    ///         ..just_asc::DEFAULT_CONFIG
+    ///     });
+    ///     grid.circle(50., 50., 48.);
+    ///     grid.to_string()
+    /// };
+    ///
+    /// for &cell_aspect in &[0., -1., f64::NAN, f64::INFINITY] {
+    ///     assert_eq!(circle(cell_aspect), circle(2.));
+    /// }
+    /// ```
+    pub cell_aspect: f64,
+
+    /// The characters to use in your drawing, one for each combination of filled regions in a
+    /// cell. How many regions a cell is split into depends on the kind of [`Tileset`]: four
+    /// quadrants for [`Tileset::Quadrants`], six for [`Tileset::Sextants`] and eight for
+    /// [`Tileset::Octants`].
     ///
     /// Some nice defaults are provided for you in the [`tilesets`] module.
     ///
     /// ### Making your own tilesets
     ///
-    /// If the quadrants were listed top-left, top-right, bottom-left, bottom-right, with a 1 for
-    /// 'filled' and a `0` for 'unfilled', then each configuration of a cell would be some binary
-    /// number < 16, e.g.
+    /// If the regions were listed left-to-right, top-to-bottom (so for quadrants: top-left,
+    /// top-right, bottom-left, bottom-right), with a 1 for 'filled' and a `0` for 'unfilled', then
+    /// each configuration of a cell would be some binary number, which is its character's index in
+    /// the tileset. For quadrants that's a number < 16, e.g.
     ///
     /// - 1010 (10): top-left and bottom-left are filled in. In [`tilesets::PURE_ASCII`] that looks
-    /// like `[`.
+    ///   like `[`.
     /// - 1101 (13): top-left, top-right and bottom-right are filled in. In
-    /// [`tilesets::PURE_ASCII`] that looks like `¶`.
+    ///   [`tilesets::PURE_ASCII`] that looks like `¶`.
     /// - 0100 (4): only top-right is filled in. In [`tilesets::PURE_ASCII`] that looks like `'`.
-    pub tileset: [char; 16],
+    pub tileset: Tileset,
 
-    /// The maximum frames per second your grid will render. Defaults to `20`.
+    /// The maximum frames per second your grid will render (at least `1`, and at most one a
+    /// nanosecond). Defaults to `20`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    ///
+    /// // the framerate the second frame aims for, and how long after the first it comes
+    /// let second_frame = |max_framerate: Option<usize>| {
+    ///     let mut seen = Vec::new();
+    ///     let config = just_asc::GridConfig {
+    ///         cell_width: 4,
+    ///         cell_height: 2,
+    ///         max_framerate,
+    ///         ..just_asc::DEFAULT_CONFIG
+    ///     };
+    ///     just_asc::frames(config, |_: &mut just_asc::Grid, frame: just_asc::Frame| {
+    ///         seen.push((frame.target_fps, frame.delta))
+    ///     })
+    ///     .take(2)
+    ///     .count();
+    ///     seen[1]
+    /// };
+    ///
+    /// assert_eq!(second_frame(Some(0)), (1, Duration::from_secs(1)));
+    /// assert_eq!(
+    ///     second_frame(Some(usize::MAX)),
+    ///     (1_000_000_000, Duration::from_nanos(1))
+    /// );
+    /// ```
     pub max_framerate: Option<usize>,
 
     /// Print a debug statement that displays a rolling average of how long it takes to render a
-    /// frame.
+    /// frame, and how many bytes each frame sends to the terminal.
     pub print_timing: bool,
 }
 
+/// How the [`Viewport`] fits onto a grid. Characters are taller than they are wide, so unless
+/// your grid happens to be the right shape (e.g. 72x36 for characters twice as tall as they're
+/// wide), _something_ has to give.
+///
+/// ```
+/// use just_asc::{Aspect, Draw, Viewport};
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 20,
+///     cell_height: 5,
+///     aspect: Aspect::Fit,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.circle(50., 50., 48.);
+///
+/// // the whole viewport fits, with room to spare on either side
+/// assert_eq!(grid.extents(), Viewport::new(-50., 0., 150., 100.));
+/// ```
+///
+/// ```text
+///       _P""""¶_
+///      d`      'b
+///      [        ]
+///      ¶,      .P
+///       "b____d"
+/// ```
+///
+/// Whereas with `Aspect::Stretch`, the same circle gets pulled out to the sides:
+///
+/// ```text
+///   ._d""""""""""b_,
+/// .P"              "¶,
+/// #                  #
+/// 'b_              _d`
+///   '"¶__________P"`
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aspect {
+    /// Squash or stretch the viewport so that it covers the whole grid, exactly. Circles are
+    /// only round if the grid's the right shape.
+    Stretch,
+
+    /// Keep things square, and make sure all of the viewport is on screen. The longer side of the
+    /// grid gets to see some extra past the viewport's edges (see [`Grid::extents`]).
+    Fit,
+
+    /// Keep things square, and make sure the viewport covers the whole grid. The shorter side of
+    /// the grid gets some of the viewport cut off (see [`Grid::extents`]).
+    Fill,
+}
+
+/// The part of the world a grid looks at: the coordinates at its left, top, right and bottom
+/// edges. The default, 0-100 both ways, is handy for doodling, but you can use whatever units
+/// your drawing's in: pixels, meters, days since the epoch...
+///
+/// Nothing says `left` has to be less than `right`, or `top` less than `bottom`. Putting the
+/// bigger y on top flips it, so y goes up the screen like it does on a graph:
+///
+/// ```
+/// use just_asc::{Draw, Viewport};
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 8,
+///     cell_height: 4,
+///     viewport: Viewport::new(-4., 2., 4., -2.),
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.line(-4., -2., 4., 2.); // up and to the right
+///
+/// assert_eq!(grid.to_string(), "      _\"\n    _\"  \n  _\"    \n_\"      \n");
+/// ```
+///
+/// Moving the camera around is a matter of handing the grid a new viewport (see
+/// [`Grid::set_viewport`]), which [`Viewport::pan`] and [`Viewport::zoom`] help with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Viewport {
+    /// The x coordinate of the left edge of the grid.
+    pub left: f64,
+    /// The y coordinate of the top edge of the grid.
+    pub top: f64,
+    /// The x coordinate of the right edge of the grid.
+    pub right: f64,
+    /// The y coordinate of the bottom edge of the grid.
+    pub bottom: f64,
+}
+
+impl Viewport {
+    /// A viewport with these left, top, right and bottom edges.
+    pub const fn new(left: f64, top: f64, right: f64, bottom: f64) -> Viewport {
+        Viewport {
+            left,
+            top,
+            right,
+            bottom,
+        }
+    }
+
+    /// The point in the middle of the viewport.
+    pub fn center(&self) -> (f64, f64) {
+        ((self.left + self.right) / 2., (self.top + self.bottom) / 2.)
+    }
+
+    /// The same viewport, slid across the world by `x` and `y`.
+    ///
+    /// ```
+    /// use just_asc::Viewport;
+    ///
+    /// let viewport = Viewport::new(0., 0., 100., 100.).pan(50., -10.);
+    /// assert_eq!(viewport, Viewport::new(50., -10., 150., 90.));
+    /// ```
+    pub fn pan(self, x: f64, y: f64) -> Viewport {
+        Viewport::new(self.left + x, self.top + y, self.right + x, self.bottom + y)
+    }
+
+    /// The same viewport, zoomed in around its center: a `factor` of `2.` makes everything look
+    /// twice as big (and shows half as much of the world each way), and `0.5` zooms back out.
+    ///
+    /// ```
+    /// use just_asc::Viewport;
+    ///
+    /// let viewport = Viewport::new(0., 0., 100., 100.).zoom(2.);
+    /// assert_eq!(viewport, Viewport::new(25., 25., 75., 75.));
+    /// ```
+    pub fn zoom(self, factor: f64) -> Viewport {
+        let (x, y) = self.center();
+        let (across, down) = (self.right - self.left, self.bottom - self.top);
+        Viewport::new(
+            x - across / factor / 2.,
+            y - down / factor / 2.,
+            x + across / factor / 2.,
+            y + down / factor / 2.,
+        )
+    }
+}
+
 /// A "good-enough" config to get started: an ASCII tileset and a 72x36 grid.
 /// ```
 /// use crate::just_asc::Draw;
@@ -162,9 +404,9 @@ pub struct GridConfig {
 /// fn main() {
 ///     just_asc::draw(
 ///         just_asc::DEFAULT_CONFIG,
-///         |grid: &mut just_asc::Grid, frame: usize| {
+///         |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
 ///             // whatever you like!
-///             # std::process::exit(0)
+///             # just_asc::Control::Stop
 ///         },
 ///     );
 /// }
@@ -172,6 +414,12 @@ pub struct GridConfig {
 pub const DEFAULT_CONFIG: GridConfig = GridConfig {
     cell_width: 72,
     cell_height: 36,
+    fit_terminal: false,
+    inline: false,
+    keyboard: false,
+    viewport: Viewport::new(0., 0., 100., 100.),
+    aspect: Aspect::Stretch,
+    cell_aspect: 2.,
     tileset: crate::tilesets::PURE_ASCII,
     max_framerate: None,
     print_timing: false,
@@ -179,6 +427,48 @@ pub const DEFAULT_CONFIG: GridConfig = GridConfig {
 
 /// The `Draw` trait defines the actual shapes you can add to your canvas. This trait is present on
 /// both [`Grid`] and [`Transform`].
+///
+/// Shapes light up every quadrant they touch, so even ones small enough to fit inside a single
+/// quadrant still show up:
+///
+/// ```
+/// use just_asc::Draw;
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 4,
+///     cell_height: 2,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.line(5., 10., 6., 11.); // top-left quadrant of the first cell
+/// grid.circle(45., 10., 1.); // top-right quadrant of the second cell
+/// grid.ellipse(81., 90., 2., 1., 0.5); // bottom-left quadrant of the last cell
+///
+/// assert_eq!(grid.to_string(), "`'  \n   ,\n");
+/// ```
+///
+/// Only the quadrants a shape actually touches light up, though. A long, thin ellipse tipped up by
+/// `PI / 4.` lies along the diagonal from the bottom-left corner to the top-right, and nothing
+/// lights up anywhere else:
+///
+/// ```
+/// use just_asc::Draw;
+/// use std::f64::consts::PI;
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 40,
+///     cell_height: 20,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.ellipse(50., 50., 40., 3., PI / 4.);
+///
+/// for (j, row) in grid.to_string().lines().enumerate() {
+///     for (i, c) in row.chars().enumerate() {
+///         // row `j` crosses the diagonal around column `40 - 2j`
+///         let off = i as i64 - (40 - 2 * j as i64);
+///         assert!(c == ' ' || off.abs() <= 6, "stray cell at row {}, column {}", j, i);
+///     }
+/// }
+/// ```
 pub trait Draw {
     /// draws a line on your [`Grid`]! the first two arguments are your starting x and y, the
     /// latter two arguments are your ending x and y.
@@ -219,6 +509,33 @@ pub trait Draw {
     ///  .d"                      "b,
     /// d"`                         "_
     /// ```
+    ///
+    /// Lines that run right along the edge of a cell show up too, and lines that meet end-to-end
+    /// join up at their corners:
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///     cell_width: 6,
+    ///     cell_height: 4,
+    ///     ..just_asc::DEFAULT_CONFIG
+    /// });
+    /// grid.line(0., 0., 100., 0.);
+    /// grid.line(100., 0., 100., 100.);
+    /// grid.line(100., 100., 0., 100.);
+    /// grid.line(0., 0., 0., 100.);
+    /// grid.line(0., 50., 100., 50.); // exactly on the edge between the 2nd and 3rd rows
+    ///
+    /// assert_eq!(
+    ///     grid.to_string(),
+    /// r#"P""""¶
+    /// [    ]
+    /// P""""¶
+    /// b____d
+    /// "#
+    /// );
+    /// ```
     fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64);
 
     /// draws ellipses. The first two parameters are the position of its center, followed
@@ -226,6 +543,11 @@ pub trait Draw {
     /// around the center (in radians). Set to `0` if you don't want to rotate! Or
     /// [`PI`](`std::f64::consts::PI`), if you're feeling spicy.
     ///
+    /// A positive keel turns the ellipse counter-clockwise on screen (with the usual y-down
+    /// [`Viewport`]), so `PI / 4.` tips its x axis up to the right. That's the opposite way from
+    /// [`Transform::rotate`], which turns things clockwise: an ellipse drawn inside a transform
+    /// rotated by `θ` comes out the same as one with `θ` taken off its keel.
+    ///
     /// ```
     /// # use std::f64::consts::PI;
     /// # use crate::just_asc::Draw;
@@ -252,6 +574,21 @@ pub trait Draw {
     ///              'b_ .__P"`
     ///                '""
     /// ```
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut turned = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// turned.with_transform(|mut transform| {
+    ///     transform.translate(50., 50.).rotate(0.5);
+    ///     transform.ellipse(0., 0., 40., 10., 0.2);
+    /// });
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// grid.ellipse(50., 50., 40., 10., 0.2 - 0.5);
+    ///
+    /// assert_eq!(turned.to_string(), grid.to_string());
+    /// ```
     fn ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64);
 
     /// draws a circle, where the first two parameters are the position of its center, and the last
@@ -288,155 +625,1212 @@ pub trait Draw {
     ///           '"""""`
     /// ```
     fn circle(&mut self, x: f64, y: f64, r: f64);
-}
-
-#[derive(Debug)]
-/// Transforms are temporary grid "wrappers" that can be freely rotated and translated (moved
-/// left-right-up-down).
-///
-/// This makes it much easier to draw groups of things that spin, orbit, or travel, and return to
-/// an unchanged grid when you're done drawing them.
-pub struct Transform<'a> {
-    grid: &'a mut Grid,
-    angle: f64,
-    angle_sin: f64,
-    angle_cos: f64,
-    x: f64,
-    y: f64,
-}
 
-impl<'a> Transform<'a> {
-    fn from(grid: &'a mut Grid) -> Transform<'a> {
-        Transform {
-            grid,
-            angle: 0.,
-            angle_sin: 0.,
-            angle_cos: 1.,
-            x: 0.,
-            y: 0.,
+    /// draws a series of connected lines, through each of the points you give it, in order.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.polyline(&[(5., 40.), (25., 10.), (45., 30.), (65., 5.), (95., 35.)]);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                                            _,
+    ///                                          .d`"b,
+    ///               __                        _P    "b,
+    ///              d`'¶_                    .P`       "b,
+    ///            .P`   '¶_                 d"           "b,
+    ///           _P       '¶_             _P`              "b,
+    ///          d`          '¶_         .d`                  "b,
+    ///        .P`             '¶_      _P                      "b,
+    ///       _P                 '¶_  .P`                         "b,
+    ///      d`                    '¶d"                             "b,
+    ///    .P`                                                        "b,
+    ///   _P                                                            "[
+    ///  d`
+    /// '`
+    /// ```
+    fn polyline(&mut self, points: &[(f64, f64)]) {
+        for pair in points.windows(2) {
+            let (x1, y1) = pair[0];
+            let (x2, y2) = pair[1];
+            self.line(x1, y1, x2, y2);
         }
     }
 
-    fn point(&self, x: f64, y: f64) -> Point {
-        Point {
-            x: x * self.angle_cos - y * self.angle_sin + self.x,
-            y: x * self.angle_sin + y * self.angle_cos + self.y,
+    /// draws the outline of a polygon, given its corners in order. Like
+    /// [`polyline`](`Draw::polyline`), except that the last corner connects back up to the first.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.polygon(&[(50., 5.), (90., 40.), (70., 40.), (50., 20.), (30., 40.), (10., 40.)]);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                             .,
+    ///                           _P""¶_
+    ///                        ._P`    '¶_,
+    ///                      .d"          "b,
+    ///                    _d"              "b_
+    ///                  _P`                  '¶_
+    ///               .d"`         _P¶_         '"b,
+    ///             .d"          _P`  '¶_          "b,
+    ///           _P"          _P`      '¶_          "¶_
+    ///        ._P`          _P`          '¶_          '¶_,
+    ///      .d"           _P`              '¶_           "b,
+    ///    _d"           _P`                  '¶_           "b_
+    ///  _P`           _P`                      '¶_           '¶_
+    /// """""""""""""""`                          '"""""""""""""""
+    /// ```
+    fn polygon(&mut self, points: &[(f64, f64)]) {
+        self.polyline(points);
+        if points.len() > 2 {
+            let (x1, y1) = points[points.len() - 1];
+            let (x2, y2) = points[0];
+            self.line(x1, y1, x2, y2);
         }
     }
 
-    /// Rotates a transform (in radians).
-    pub fn rotate(&mut self, radians: f64) -> &mut Self {
-        let new_angle = self.angle + radians;
-        self.angle = new_angle;
-        self.angle_sin = new_angle.sin();
-        self.angle_cos = new_angle.cos();
-        self
-    }
-
-    /// Moves a transform along x and y axes.
-    pub fn translate(&mut self, x: f64, y: f64) -> &mut Self {
-        self.x += x;
-        self.y += y;
-        self
+    /// draws the outline of a rectangle, where the first two parameters are the position of its
+    /// top-left corner, followed by its width and height.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.rect(10., 10., 80., 20.);
+    /// grid.rect(30., 15., 40., 10.);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    /// __________________________________________________________
+    /// [                                                        ]
+    /// [             ]""""""""""""""""""""""""""""[             ]
+    /// [             ]                            [             ]
+    /// [             ]                            [             ]
+    /// [             ]                            [             ]
+    /// [             '""""""""""""""""""""""""""""`             ]
+    /// b________________________________________________________d
+    /// ```
+    fn rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.polygon(&[
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ]);
     }
-}
 
-impl<'a> Draw for Transform<'a> {
-    fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
-        let p1 = self.point(x1, y1);
-        let p2 = self.point(x2, y2);
-        self.grid.line(p1.x, p1.y, p2.x, p2.y);
-    }
-    fn ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64) {
-        let p = self.point(x, y);
-        self.grid.ellipse(p.x, p.y, a, b, self.angle + keel);
-    }
-    fn circle(&mut self, x: f64, y: f64, r: f64) {
-        let p = self.point(x, y);
-        self.grid.circle(p.x, p.y, r);
-    }
-}
+    /// draws part of a circle: the stretch of its outline between two angles (in radians). An
+    /// angle of `0` points right, and angles go clockwise from there, so [`PI`](`std::f64::consts::PI`)
+    /// `/ 2.` points straight down.
+    ///
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.arc(50., 50., 40., PI, 2. * PI); // the top half of a gauge
+    /// grid.line(50., 50., 25., 20.); // the needle
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                       ______________
+    ///                 ._d"""`            '"""b_,
+    ///              _d""                        ""b_
+    ///           _d"`                              '"b_
+    ///         _P`\,                                  '¶_
+    ///       .P`   "b                                   '¶,
+    ///      d"       ¶_                                   "b
+    ///    .P`         '\,                                  '¶,
+    ///   .P             "b                                   ¶,
+    ///  .P                ¶_                                  ¶,
+    ///  d                  '\,                                 b
+    /// .[                    "b                                ],
+    /// d                       ¶_                               b
+    /// [                        '\,                             ]
+    /// [                          "b,                           ]
+    /// `                           '`                           '
+    /// ```
+    ///
+    /// The arc always runs clockwise from `start` to `end`, so it can cross `0` on the way, and
+    /// swapping the two gets you the rest of the circle instead. (An `end` a whole turn or more
+    /// past `start` draws the whole thing.)
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    /// use std::f64::consts::PI;
+    ///
+    /// let arc = |start: f64, end: f64| {
+    ///     let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///         cell_width: 20,
+    ///         cell_height: 10,
+    ///         ..just_asc::DEFAULT_CONFIG
+    ///     });
+    ///     grid.arc(50., 50., 40., start, end);
+    ///     grid.to_string()
+    /// };
+    /// let blank = |text: &str, columns: std::ops::Range<usize>| {
+    ///     text.lines()
+    ///         .all(|line| line.chars().skip(columns.start).take(columns.len()).all(|c| c == ' '))
+    /// };
+    ///
+    /// // from straight up, round through 0 to straight down: the right half
+    /// let right = arc(3. * PI / 2., PI / 2.);
+    /// assert!(blank(&right, 0..8) && !blank(&right, 12..20));
+    ///
+    /// // and the other way round, the left half
+    /// let left = arc(PI / 2., 3. * PI / 2.);
+    /// assert!(blank(&left, 12..20) && !blank(&left, 0..8));
+    /// ```
+    fn arc(&mut self, x: f64, y: f64, r: f64, start: f64, end: f64);
 
-#[derive(Debug)]
-/// A Grid is what you draw on. Rather than concerning yourself with each character and how its
-/// position maps to your drawing, a Grid gives you a 100x100 canvas, with (0,0) in the top-left
-/// corner.
-///
-/// ```
-/// # use std::f64::consts::PI;
-/// # use crate::just_asc::Draw;
-/// #
-/// # fn main() {
-/// #     just_asc::once(
-/// #         just_asc::DEFAULT_CONFIG,
-/// #         |grid: &mut just_asc::Grid| {
-/// grid.line(0., 0., 100., 100.); // a line from top-left to bottom-right
-/// grid.circle(50., 50., 25.); // a circle dead center
-/// #         },
-/// #     );
-/// # }
-/// ```
-pub struct Grid {
-    grid: Vec<Vec<Cell>>,
-    tileset: [char; 16],
-    max_framerate: usize,
-    print_timing: bool,
-}
+    /// draws part of an ellipse. The first five parameters are the same as
+    /// [`ellipse`](`Draw::ellipse`), and the last two are the angles to start and end at, measured
+    /// around the ellipse before it's turned by its keel.
+    ///
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.elliptical_arc(50., 30., 40., 20., 0., 0., PI); // a smile
+    /// grid.elliptical_arc(50., 30., 40., 20., PI / 8., 0., PI); // a lopsided smile
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                                                        ],
+    ///                                                         [
+    ///                                                        .[
+    ///                                                        d
+    ///                                                       d`
+    /// ,                                                   .P`  .
+    /// b                                                  d"    d
+    /// 'b                                              ._P`    d`
+    ///  'b,                                          .d"     .d`
+    ///    "b_                                     ._P"     _d"
+    ///      '"b_,                              ._P"    ._d"`
+    ///   ¶,     ""b__,                     ._d""  .__d""
+    ///    ¶_,        """"b_______________###__d""""
+    ///      "¶__,              ____d"""
+    ///          """""""""""""""`
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn elliptical_arc(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64, start: f64, end: f64);
 
-// put a _tiny_ bit of padding on the edges so lines at the edges register
-const BUMPER: f64 = 0.00001;
+    /// draws a quadratic Bézier curve: it starts at the first point, bends towards the second (the
+    /// "control point"), and ends at the third.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.quadratic_bezier(10., 40., 50., -20., 90., 40.);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                        ____________
+    ///                   ._d""`          '""b_,
+    ///                _d""                    ""b_
+    ///             ._P`                          '¶_,
+    ///           .d"                                "b,
+    ///         .d"                                    "b,
+    ///       .d"                                        "b,
+    ///      d"                                            "b
+    ///    .P`                                              '¶,
+    ///   d"                                                  "b
+    /// .P`                                                    '¶,
+    /// "                                                        "
+    /// ```
+    fn quadratic_bezier(&mut self, x1: f64, y1: f64, cx: f64, cy: f64, x2: f64, y2: f64);
 
-impl Draw for Grid {
-    fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
-        let line = Line::new(Point::new(x1, y1), Point::new(x2, y2));
-        self.each_cell_mut(|cell| {
-            cell.render_line(&line);
-        });
+    /// draws a cubic Bézier curve: it starts at the first point heading towards the second, comes
+    /// in from the direction of the third, and ends at the fourth.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.cubic_bezier(5., 40., 40., -20., 60., 80., 95., 10.);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                                                                 .,
+    ///                                                                .P
+    ///                                                               .P
+    ///                                                              .P
+    ///            .__P"""""""b__                                   d"
+    ///          _P"            '"¶__                             .d`
+    ///       .d"`                  '"b_                         _P
+    ///      _P                        '"b_,                   _P`
+    ///    .P`                             "¶__             .d"`
+    ///   d"                                  '""b________d""
+    ///  d`
+    /// '`
+    /// ```
+    #[allow(clippy::too_many_arguments)]
+    fn cubic_bezier(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        cx1: f64,
+        cy1: f64,
+        cx2: f64,
+        cy2: f64,
+        x2: f64,
+        y2: f64,
+    );
+
+    /// draws a filled-in circle: like [`circle`](`Draw::circle`), but every quadrant inside it is
+    /// filled too.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.fill_circle(30., 30., 20.);
+    /// grid.circle(60., 60., 20.);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///          ._________,
+    ///       _d##############_,
+    ///    .d###################b,
+    ///   _#######################b
+    ///  d#########################b
+    /// ]###########################b
+    /// #############################
+    /// #############################
+    /// #############################
+    /// ¶############################
+    /// '###########################`
+    ///  '#########################`  ___d""""¶___
+    ///   '¶######################`_d"`          '"¶_,
+    ///     '¶#################P"_P`                 "b,
+    ///        ""###########"" .P`                     "b
+    ///                       .P                        'b
+    ///                       P                          ],
+    ///                      ]`                           b
+    ///                      ]                            ]
+    ///                      ],                           P
+    ///                       b                          .[
+    ///                       'b                        .P
+    ///                        'b                      .P
+    ///                         '¶_                  .d"
+    ///                           '¶__            ._P"
+    ///                              '""b_______P""
+    /// ```
+    fn fill_circle(&mut self, x: f64, y: f64, r: f64);
+
+    /// draws a filled-in ellipse. Takes the same parameters as [`ellipse`](`Draw::ellipse`).
+    ///
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.fill_ellipse(40., 20., 30., 10., 0.);
+    /// grid.fill_ellipse(70., 30., 30., 10., PI / 4.);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                                                      _____,
+    ///            .____________________                 __########[
+    ///     .__d############################b__      ._############[
+    ///  _d######################################_,_d##############[
+    /// d#########################################################P
+    /// #########################################################P
+    /// '¶######################################################P
+    ///    ""##################################################P
+    ///         """""#################P""#####################`
+    ///                                .d###################"
+    ///                               .###################P`
+    ///                              d##################P`
+    ///                             d#################P`
+    ///                            .################"
+    ///                            ##############P`
+    ///                            ###########P"
+    ///                            '######""`
+    /// ```
+    fn fill_ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64);
+
+    /// draws a filled-in polygon, given its corners in order. The last corner connects back up to
+    /// the first, so there's no need to repeat it. Polygons can be any shape you like, even ones
+    /// that cross over themselves (overlapping parts are left empty).
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.fill_polygon(&[(50., 5.), (90., 40.), (70., 40.), (50., 20.), (30., 40.), (10., 40.)]);
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    ///                             .,
+    ///                           _####_
+    ///                        ._########_,
+    ///                      .d############b,
+    ///                    _d################b_
+    ///                  _######################_
+    ///               .d############P¶############b,
+    ///             .d############P`  '¶############b,
+    ///           _#############P`      '¶#############_
+    ///        ._#############P`          '¶#############_,
+    ///      .d#############P`              '¶#############b,
+    ///    _d#############P`                  '¶#############b_
+    ///  _##############P`                      '¶##############_
+    /// """""""""""""""`                          '"""""""""""""""
+    /// ```
+    fn fill_polygon(&mut self, points: &[(f64, f64)]);
+
+    /// draws a filled-in rectangle, where the first two parameters are the position of its
+    /// top-left corner, followed by its width and height.
+    ///
+    /// ```
+    /// # use crate::just_asc::Draw;
+    /// #
+    /// # fn main() {
+    /// #     just_asc::once(
+    /// #         just_asc::DEFAULT_CONFIG,
+    /// #         |grid: &mut just_asc::Grid| {
+    /// grid.fill_rect(10., 10., 80., 5.); // a progress bar, all done
+    /// grid.fill_rect(10., 20., 50., 5.); // a progress bar, a little more than halfway there
+    /// #         },
+    /// #     );
+    /// # }
+    /// ```
+    ///
+    /// ```text
+    /// __________________________________________________________
+    /// ##########################################################
+    /// """"""""""""""""""""""""""""""""""""""""""""""""""""""""""
+    ///
+    /// ####################################[
+    /// ####################################[
+    /// """"""""""""""""""""""""""""""""""""`
+    /// ```
+    fn fill_rect(&mut self, x: f64, y: f64, width: f64, height: f64) {
+        self.fill_polygon(&[
+            (x, y),
+            (x + width, y),
+            (x + width, y + height),
+            (x, y + height),
+        ]);
+    }
+
+    /// picks the [`Color`] for everything you draw after this (until you pick another). Colors are
+    /// reset to [`Color::Default`] whenever the grid is cleared, e.g. at the start of every frame.
+    ///
+    /// Each character on the grid can only be one color, so when shapes of different colors share
+    /// a cell, the last one drawn there wins.
+    ///
+    /// ```
+    /// use just_asc::{Color, Draw};
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///     cell_width: 4,
+    ///     cell_height: 1,
+    ///     ..just_asc::DEFAULT_CONFIG
+    /// });
+    /// grid.set_color(Color::RED);
+    /// grid.fill_rect(0., 0., 45., 100.);
+    /// grid.set_color(Color::Rgb(0, 128, 255));
+    /// grid.fill_rect(80., 0., 20., 100.);
+    ///
+    /// assert_eq!(
+    ///     grid.to_string(),
+    ///     "\x1b[31m## \x1b[38;2;0;128;255m#\x1b[39m\n"
+    /// );
+    /// ```
+    fn set_color(&mut self, color: Color);
+}
+
+#[derive(Debug)]
+/// Transforms are temporary grid "wrappers" that can be freely moved, rotated, scaled, skewed and
+/// mirrored.
+///
+/// This makes it much easier to draw groups of things that spin, orbit, travel or grow, and return
+/// to an unchanged grid when you're done drawing them.
+///
+/// A transform has an origin (where `(0, 0)` ends up on the grid) that [`Transform::translate`]
+/// moves around, always in the grid's own units and directions. Everything else (rotating,
+/// scaling, skewing) happens _around_ that origin, and stacks up: rotate twice and you've rotated
+/// by both.
+///
+/// Transforms can have transforms of their own, too (see [`Transform::transform`]), for things
+/// that move relative to other moving things.
+///
+/// ```
+/// use just_asc::Draw;
+///
+/// let mut squashed = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+/// squashed.with_transform(|mut transform| {
+///     transform.translate(50., 50.).scale(2., 1.);
+///     transform.circle(0., 0., 20.);
+/// });
+///
+/// // a circle that's been stretched out is an ellipse
+/// let mut ellipse = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+/// ellipse.ellipse(50., 50., 40., 20., 0.);
+///
+/// assert_eq!(squashed.to_string(), ellipse.to_string());
+/// ```
+pub struct Transform<'a> {
+    grid: &'a mut Grid,
+    // the transform this one's inside of (if any), and this one's own moves on top of it
+    parent: Matrix,
+    local: Matrix,
+    // the two together: what actually happens to the shapes we draw
+    matrix: Matrix,
+}
+
+impl<'a> Transform<'a> {
+    fn from(grid: &'a mut Grid) -> Transform<'a> {
+        Transform::within(grid, Matrix::IDENTITY)
+    }
+
+    fn within(grid: &'a mut Grid, parent: Matrix) -> Transform<'a> {
+        Transform {
+            grid,
+            parent,
+            local: Matrix::IDENTITY,
+            matrix: parent,
+        }
+    }
+
+    fn set_local(&mut self, local: Matrix) -> &mut Self {
+        self.local = local;
+        self.matrix = self.parent * local;
+        self
+    }
+
+    fn point(&self, x: f64, y: f64) -> Point {
+        let (x, y) = self.matrix.apply(x, y);
+        Point { x, y }
+    }
+
+    /// Rotates a transform (in radians).
+    pub fn rotate(&mut self, radians: f64) -> &mut Self {
+        self.apply(Matrix::rotation(radians))
+    }
+
+    /// Moves a transform along x and y axes. (For a transform inside another transform, that's
+    /// the outer one's x and y: see [`Transform::transform`].)
+    pub fn translate(&mut self, x: f64, y: f64) -> &mut Self {
+        self.set_local(Matrix::translation(x, y) * self.local)
+    }
+
+    /// Stretches a transform `x` times as wide and `y` times as tall. Use a negative scale to
+    /// mirror things, like `scale(-1., 1.)` to flip a sprite so it faces the other way.
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut mirrored = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// mirrored.with_transform(|mut transform| {
+    ///     transform.translate(50., 50.).scale(-1., 1.);
+    ///     transform.polygon(&[(10., 0.), (40., -20.), (40., 20.)]);
+    /// });
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// grid.polygon(&[(40., 50.), (10., 30.), (10., 70.)]);
+    ///
+    /// assert_eq!(mirrored.to_string(), grid.to_string());
+    /// ```
+    pub fn scale(&mut self, x: f64, y: f64) -> &mut Self {
+        self.apply(Matrix::scaling(x, y))
+    }
+
+    /// Leans a transform over: vertical lines tip by `x` radians, and horizontal ones by `y`
+    /// radians.
+    pub fn skew(&mut self, x: f64, y: f64) -> &mut Self {
+        self.apply(Matrix::skewing(x, y))
+    }
+
+    /// Adds any [`Matrix`] you like to a transform. Like rotating and scaling, it happens around
+    /// the transform's origin (so a translation in here moves in the transform's units, and in its
+    /// directions, rather than the grid's).
+    pub fn apply(&mut self, matrix: Matrix) -> &mut Self {
+        self.set_local(self.local * matrix)
+    }
+
+    /// Everything this transform does (including what any transforms it's inside of do), as a
+    /// single [`Matrix`].
+    pub fn matrix(&self) -> Matrix {
+        self.matrix
+    }
+
+    /// Creates a transform _inside_ this one: it starts out doing everything this one does, and
+    /// whatever you do to it happens on top of that, in this transform's units and directions.
+    /// This one's left alone, so once you're done with the inner transform you can carry on where
+    /// you left off.
+    ///
+    /// That makes things that move relative to other moving things, like moons orbiting planets
+    /// orbiting suns (or elbows, wrists and fingers), a matter of nesting transforms:
+    ///
+    /// ```
+    /// # use std::f64::consts::PI;
+    /// use just_asc::Draw;
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// let mut sun = grid.transform();
+    /// sun.translate(50., 50.).rotate(PI / 2.);
+    /// sun.fill_circle(0., 0., 10.);
+    ///
+    /// let mut planet = sun.transform();
+    /// planet.translate(30., 0.).rotate(PI / 2.);
+    /// planet.fill_circle(0., 0., 4.);
+    ///
+    /// let mut moon = planet.transform();
+    /// moon.translate(10., 0.);
+    /// moon.fill_circle(0., 0., 2.);
+    ///
+    /// // a quarter turn around the sun (down the screen), then a quarter turn around the planet
+    /// let mut expected = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// expected.fill_circle(50., 50., 10.);
+    /// expected.fill_circle(50., 80., 4.);
+    /// expected.fill_circle(40., 80., 2.);
+    ///
+    /// assert_eq!(grid.to_string(), expected.to_string());
+    /// ```
+    pub fn transform(&mut self) -> Transform<'_> {
+        Transform::within(self.grid, self.matrix)
+    }
+
+    /// Takes a closure which _receives_ a transform inside this one (see
+    /// [`Transform::transform`]), like [`Grid::with_transform`] does for grids.
+    pub fn with_transform<F>(&mut self, f: F)
+    where
+        F: FnOnce(Transform),
+    {
+        f(self.transform())
+    }
+
+    // what an ellipse turns into: its new axes and keel, plus how to find where an angle around
+    // the old ellipse ends up on the new one (see `Arc`)
+    fn ellipse_axes(
+        &self,
+        a: f64,
+        b: f64,
+        keel: f64,
+    ) -> (f64, f64, f64, impl Fn(f64, f64) -> (f64, f64)) {
+        // the points around an ellipse are `rotation(-keel) * scaling(a, b) * (cos t, sin t)`, so
+        // transformed, they're this times `(cos t, sin t)`. Splitting that back up into a
+        // rotation and a scale gets us an ellipse again.
+        let (after, a, b, before) =
+            (self.matrix.linear() * Matrix::rotation(-keel) * Matrix::scaling(a, b)).decompose();
+
+        // the rotation before the scale just shifts the angles around, and mirroring (a negative
+        // scale) runs them backwards, so an arc has to go round from the other end
+        let mirrored = b < 0.;
+        let angles = move |start: f64, end: f64| match mirrored {
+            false => (start + before, end + before),
+            true => (-(end + before), -(start + before)),
+        };
+        (a, b.abs(), -after, angles)
+    }
+
+    // how much a transform scales circles, if it keeps them circles
+    fn circle_scale(&self) -> Option<f64> {
+        let (_, x, y, _) = self.matrix.decompose();
+        if x - y.abs() <= x * 0.000000000001 {
+            Some(x)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> Draw for Transform<'a> {
+    fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let p1 = self.point(x1, y1);
+        let p2 = self.point(x2, y2);
+        self.grid.line(p1.x, p1.y, p2.x, p2.y);
+    }
+    fn ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64) {
+        let p = self.point(x, y);
+        let (a, b, keel, _) = self.ellipse_axes(a, b, keel);
+        self.grid.ellipse(p.x, p.y, a, b, keel);
+    }
+    fn circle(&mut self, x: f64, y: f64, r: f64) {
+        match self.circle_scale() {
+            Some(scale) => {
+                let p = self.point(x, y);
+                self.grid.circle(p.x, p.y, r * scale);
+            }
+            None => self.ellipse(x, y, r, r, 0.),
+        }
+    }
+    fn arc(&mut self, x: f64, y: f64, r: f64, start: f64, end: f64) {
+        self.elliptical_arc(x, y, r, r, 0., start, end);
+    }
+    fn elliptical_arc(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64, start: f64, end: f64) {
+        let p = self.point(x, y);
+        let (a, b, keel, angles) = self.ellipse_axes(a, b, keel);
+        let (start, end) = angles(start, end);
+        self.grid.elliptical_arc(p.x, p.y, a, b, keel, start, end);
+    }
+    fn quadratic_bezier(&mut self, x1: f64, y1: f64, cx: f64, cy: f64, x2: f64, y2: f64) {
+        let p1 = self.point(x1, y1);
+        let c = self.point(cx, cy);
+        let p2 = self.point(x2, y2);
+        self.grid.quadratic_bezier(p1.x, p1.y, c.x, c.y, p2.x, p2.y);
+    }
+    fn cubic_bezier(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        cx1: f64,
+        cy1: f64,
+        cx2: f64,
+        cy2: f64,
+        x2: f64,
+        y2: f64,
+    ) {
+        let p1 = self.point(x1, y1);
+        let c1 = self.point(cx1, cy1);
+        let c2 = self.point(cx2, cy2);
+        let p2 = self.point(x2, y2);
+        self.grid
+            .cubic_bezier(p1.x, p1.y, c1.x, c1.y, c2.x, c2.y, p2.x, p2.y);
+    }
+    fn fill_circle(&mut self, x: f64, y: f64, r: f64) {
+        match self.circle_scale() {
+            Some(scale) => {
+                let p = self.point(x, y);
+                self.grid.fill_circle(p.x, p.y, r * scale);
+            }
+            None => self.fill_ellipse(x, y, r, r, 0.),
+        }
+    }
+    fn fill_ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64) {
+        let p = self.point(x, y);
+        let (a, b, keel, _) = self.ellipse_axes(a, b, keel);
+        self.grid.fill_ellipse(p.x, p.y, a, b, keel);
+    }
+    fn set_color(&mut self, color: Color) {
+        self.grid.set_color(color);
+    }
+    fn fill_polygon(&mut self, points: &[(f64, f64)]) {
+        let points: Vec<(f64, f64)> = points
+            .iter()
+            .map(|&(x, y)| {
+                let p = self.point(x, y);
+                (p.x, p.y)
+            })
+            .collect();
+        self.grid.fill_polygon(&points);
+    }
+}
+
+#[derive(Debug)]
+/// A Grid is what you draw on. Rather than concerning yourself with each character and how its
+/// position maps to your drawing, a Grid gives you a 100x100 canvas, with (0,0) in the top-left
+/// corner (unless you give it a different [`Viewport`]).
+///
+/// ```
+/// # use std::f64::consts::PI;
+/// # use crate::just_asc::Draw;
+/// #
+/// # fn main() {
+/// #     just_asc::once(
+/// #         just_asc::DEFAULT_CONFIG,
+/// #         |grid: &mut just_asc::Grid| {
+/// grid.line(0., 0., 100., 100.); // a line from top-left to bottom-right
+/// grid.circle(50., 50., 25.); // a circle dead center
+/// #         },
+/// #     );
+/// # }
+/// ```
+pub struct Grid {
+    grid: Vec<Vec<Cell>>,
+    x_axis: Axis,
+    y_axis: Axis,
+    viewport: Viewport,
+    // the part of the world we can actually see, once the aspect has had its way
+    extents: Viewport,
+    aspect: Aspect,
+    cell_aspect: f64,
+    tileset: Tileset,
+    color: Color,
+    max_framerate: u32,
+    print_timing: bool,
+    fit_terminal: bool,
+    // how many times the terminal had been resized when we last fit it (see `terminal::resizes`)
+    resizes: usize,
+    // how many times we'd carried on after Ctrl-Z when we last printed (see `terminal::resumes`)
+    resumes: usize,
+    // how many rows an inline grid asked for (`None` if it's not inline)
+    inline_rows: Option<usize>,
+    keyboard: bool,
+    // the keys pressed since the last frame
+    keys: Vec<Key>,
+    // what's on the terminal right now (as far as we know), so we only have to redraw the cells
+    // that changed. Empty until the first frame goes out.
+    screen: Vec<Vec<(char, Color)>>,
+}
+
+// the grid size that fills the terminal, leaving a line underneath for the cursor to sit on (and
+// one more for the timing, if we're printing it) so nothing scrolls. Inline grids only get as
+// many rows as they asked for.
+fn terminal_fit(print_timing: bool, inline_rows: Option<usize>) -> Option<(usize, usize)> {
+    let (columns, rows) = terminal::size()?;
+    let spare = if print_timing { 2 } else { 1 };
+    let rows = rows.saturating_sub(spare).max(1);
+    Some((
+        columns,
+        inline_rows.map_or(rows, |inline_rows| inline_rows.min(rows)),
+    ))
+}
+
+// put a _tiny_ bit of padding on the edges so lines at the edges register (for every 100 units
+// of the world we can see)
+const BUMPER: f64 = 0.00001;
+
+// how one axis of the world is split up into cells
+#[derive(Debug, Clone, Copy)]
+struct Axis {
+    // where the first cell starts, and how wide each one is. These are in world units, so on a
+    // flipped axis the unit is negative.
+    origin: f64,
+    unit: f64,
+    // the padding, scaled to the viewport: the same, relative to the cells, on both axes and at
+    // any zoom, so lines through cell corners come out the same wherever you're looking
+    bumper: f64,
+    count: usize,
+}
+
+impl Axis {
+    // `count` cells, from one edge of the world we can see to the other
+    fn new(from: f64, to: f64, count: usize) -> Axis {
+        let span = to - from;
+        let bumper = BUMPER * (span.abs() / 100.);
+        Axis {
+            origin: from - bumper.copysign(span) / 2.,
+            unit: (span + bumper.copysign(span)) / count as f64,
+            bumper,
+            count,
+        }
+    }
+
+    // where cell `i` starts
+    fn start(&self, i: usize) -> f64 {
+        self.origin + i as f64 * self.unit
+    }
+
+    // the edges of cell `i`, smallest first
+    fn bounds(&self, i: usize) -> (f64, f64) {
+        let (start, end) = (self.start(i), self.start(i) + self.unit);
+        (start.min(end), start.max(end))
+    }
+
+    // maps a span of coordinates to the (clamped) range of cells it covers. The span is padded by
+    // the bumper on each side, so float error never skips a cell we should check.
+    fn cells_between(&self, from: f64, to: f64) -> Range<usize> {
+        let index = |n: f64| n.floor().max(0.).min(self.count as f64) as usize;
+        if from.is_nan() || to.is_nan() || from > to {
+            return 0..0;
+        }
+
+        let (start, end) = (
+            (from - self.origin) / self.unit,
+            (to - self.origin) / self.unit,
+        );
+        let bumper = self.bumper / self.unit.abs();
+        index(start.min(end) - bumper)..index(start.max(end) + bumper + 1.)
+    }
+}
+
+impl Draw for Grid {
+    fn line(&mut self, x1: f64, y1: f64, x2: f64, y2: f64) {
+        let line = Line::new(Point::new(x1, y1), Point::new(x2, y2));
+        for j in self.rows_between(y1.min(y2), y1.max(y2)) {
+            let (top, bottom) = self.row_bounds(j);
+            let (from, to) = line.x_span(top, bottom);
+            self.each_cell_between_mut(j, from, to, |cell| cell.render(|r| r.overlaps_line(&line)));
+        }
     }
 
     fn ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64) {
         let ellipse = Ellipse::new(Point::new(x, y), a, b, keel);
-        self.each_cell_mut(|cell| {
-            cell.render_ellipse(&ellipse);
-        });
+        let (top_left, bottom_right) = ellipse.bounds();
+        for j in self.rows_between(top_left.y, bottom_right.y) {
+            self.each_cell_between_mut(j, top_left.x, bottom_right.x, |cell| {
+                cell.render(|r| r.overlaps_ellipse(&ellipse))
+            });
+        }
     }
 
     fn circle(&mut self, x: f64, y: f64, r: f64) {
         let circle = Circle::new(Point::new(x, y), r);
-        self.each_cell_mut(|cell| {
-            cell.render_circle(&circle);
-        });
+        let (top, bottom) = circle.y_span();
+        for j in self.rows_between(top, bottom) {
+            let (row_top, row_bottom) = self.row_bounds(j);
+            let [(from_1, to_1), (from_2, to_2)] = circle.x_spans(row_top, row_bottom);
+            if to_1 + BUMPER >= from_2 - BUMPER {
+                self.each_cell_between_mut(j, from_1, to_2, |cell| {
+                    cell.render(|r| r.overlaps_circle(&circle))
+                });
+            } else {
+                self.each_cell_between_mut(j, from_1, to_1, |cell| {
+                    cell.render(|r| r.overlaps_circle(&circle))
+                });
+                self.each_cell_between_mut(j, from_2, to_2, |cell| {
+                    cell.render(|r| r.overlaps_circle(&circle))
+                });
+            }
+        }
+    }
+    fn arc(&mut self, x: f64, y: f64, r: f64, start: f64, end: f64) {
+        self.elliptical_arc(x, y, r, r, 0., start, end);
+    }
+
+    fn elliptical_arc(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64, start: f64, end: f64) {
+        let arc = Arc::new(Ellipse::new(Point::new(x, y), a, b, keel), start, end);
+        let (top_left, bottom_right) = arc.bounds();
+        for j in self.rows_between(top_left.y, bottom_right.y) {
+            self.each_cell_between_mut(j, top_left.x, bottom_right.x, |cell| {
+                cell.render(|r| r.overlaps_arc(&arc))
+            });
+        }
+    }
+
+    fn quadratic_bezier(&mut self, x1: f64, y1: f64, cx: f64, cy: f64, x2: f64, y2: f64) {
+        let bezier = Bezier::quadratic(Point::new(x1, y1), Point::new(cx, cy), Point::new(x2, y2));
+        self.render_bezier(&bezier);
+    }
+
+    fn cubic_bezier(
+        &mut self,
+        x1: f64,
+        y1: f64,
+        cx1: f64,
+        cy1: f64,
+        cx2: f64,
+        cy2: f64,
+        x2: f64,
+        y2: f64,
+    ) {
+        let bezier = Bezier::cubic(
+            Point::new(x1, y1),
+            Point::new(cx1, cy1),
+            Point::new(cx2, cy2),
+            Point::new(x2, y2),
+        );
+        self.render_bezier(&bezier);
+    }
+
+    fn fill_circle(&mut self, x: f64, y: f64, r: f64) {
+        let circle = Circle::new(Point::new(x, y), r);
+        let (top, bottom) = circle.y_span();
+        for j in self.rows_between(top, bottom) {
+            let (row_top, row_bottom) = self.row_bounds(j);
+            let [(from, _), (_, to)] = circle.x_spans(row_top, row_bottom);
+            self.each_cell_between_mut(j, from, to, |cell| {
+                cell.render(|r| r.overlaps_filled_circle(&circle))
+            });
+        }
+    }
+
+    fn fill_ellipse(&mut self, x: f64, y: f64, a: f64, b: f64, keel: f64) {
+        let ellipse = Ellipse::new(Point::new(x, y), a, b, keel);
+        let (top_left, bottom_right) = ellipse.bounds();
+        for j in self.rows_between(top_left.y, bottom_right.y) {
+            self.each_cell_between_mut(j, top_left.x, bottom_right.x, |cell| {
+                cell.render(|r| r.overlaps_filled_ellipse(&ellipse))
+            });
+        }
+    }
+
+    fn set_color(&mut self, color: Color) {
+        self.color = color;
+    }
+
+    fn fill_polygon(&mut self, points: &[(f64, f64)]) {
+        let points: Vec<Point> = points.iter().map(|&(x, y)| Point::new(x, y)).collect();
+        let polygon = Polygon::new(&points);
+        let (top_left, bottom_right) = polygon.bounds();
+        for j in self.rows_between(top_left.y, bottom_right.y) {
+            self.each_cell_between_mut(j, top_left.x, bottom_right.x, |cell| {
+                cell.render(|r| r.overlaps_polygon(&polygon))
+            });
+        }
     }
 }
 
 impl Grid {
-    fn new(config: GridConfig) -> Grid {
-        let cell_width = config.cell_width;
-        let cell_height = config.cell_height;
-        let x_unit = (100. + BUMPER) / cell_width as f64;
-        let y_unit = (100. + BUMPER) / cell_height as f64;
+    /// Creates a blank Grid from a [`GridConfig`]. You usually won't need this: [`draw`] and
+    /// [`once`] make one for you. It comes in handy when you want to render somewhere other than
+    /// stdout, though (see [`Grid::render_to`]).
+    pub fn new(config: GridConfig) -> Grid {
+        let mut size = (config.cell_width, config.cell_height);
+        let inline_rows = Some(config.cell_height).filter(|_| config.inline);
+        if config.fit_terminal {
+            size = terminal_fit(config.print_timing, inline_rows).unwrap_or(size);
+        }
 
-        Grid {
+        let mut grid = Grid {
             tileset: config.tileset,
-            max_framerate: config.max_framerate.unwrap_or(20),
+            color: Color::Default,
+            max_framerate: framerate(config.max_framerate.unwrap_or(20)),
             print_timing: config.print_timing,
-            grid: (0..cell_height)
+            fit_terminal: config.fit_terminal,
+            resizes: terminal::resizes(),
+            resumes: terminal::resumes(),
+            inline_rows,
+            keyboard: config.keyboard,
+            keys: Vec::new(),
+            screen: Vec::new(),
+            x_axis: Axis::new(0., 0., 0),
+            y_axis: Axis::new(0., 0., 0),
+            viewport: config.viewport,
+            extents: config.viewport,
+            aspect: config.aspect,
+            cell_aspect: Some(config.cell_aspect)
+                .filter(|cell_aspect| cell_aspect.is_finite() && *cell_aspect > 0.)
+                .unwrap_or(DEFAULT_CONFIG.cell_aspect),
+            grid: Vec::new(),
+        };
+        grid.resize(size.0, size.1);
+        grid
+    }
+
+    // lays the cells out for a new width and height (in characters), or a new viewport, leaving
+    // them blank
+    fn resize(&mut self, cell_width: usize, cell_height: usize) {
+        let viewport = self.viewport;
+        let (across, down) = (
+            viewport.right - viewport.left,
+            viewport.bottom - viewport.top,
+        );
+        // to keep things square, the same amount of the world has to fit in the width of a
+        // character both ways: enough to fit the whole viewport, or just enough to fill the grid
+        let width = cell_width as f64;
+        let height = cell_height as f64 * self.cell_aspect;
+        let scale = match self.aspect {
+            Aspect::Stretch => None,
+            Aspect::Fit => Some((across.abs() / width).max(down.abs() / height)),
+            Aspect::Fill => Some((across.abs() / width).min(down.abs() / height)),
+        };
+        let (across, down) = match scale {
+            None => (across, down),
+            Some(scale) => (
+                (scale * width).copysign(across),
+                (scale * height).copysign(down),
+            ),
+        };
+        let (x, y) = viewport.center();
+        let extents = Viewport::new(
+            x - across / 2.,
+            y - down / 2.,
+            x + across / 2.,
+            y + down / 2.,
+        );
+
+        let x_axis = Axis::new(extents.left, extents.right, cell_width);
+        let y_axis = Axis::new(extents.top, extents.bottom, cell_height);
+        let (columns, rows) = self.tileset.layout();
+        // the top-left and bottom-right corners of cell `i`, `j`
+        let corners = |i: usize, j: usize| {
+            let (x, y) = (x_axis.start(i), y_axis.start(j));
+            (
+                Point::new(x, y),
+                Point::new(x + x_axis.unit, y + y_axis.unit),
+            )
+        };
+
+        self.x_axis = x_axis;
+        self.y_axis = y_axis;
+        self.extents = extents;
+        if self.grid.len() == cell_height && self.grid.iter().all(|row| row.len() == cell_width) {
+            // same cells, different part of the world: move them, rather than making new ones
+            // (which adds up, if you're panning around every frame)
+            for (j, row) in self.grid.iter_mut().enumerate() {
+                for (i, cell) in row.iter_mut().enumerate() {
+                    let (p1, p2) = corners(i, j);
+                    cell.place(p1, p2, columns, rows);
+                }
+            }
+        } else {
+            self.grid = (0..cell_height)
                 .map(|j| {
                     (0..cell_width)
                         .map(|i| {
-                            let x = i as f64 * x_unit - BUMPER / 2.;
-                            let y = j as f64 * y_unit - BUMPER / 2.;
-                            Cell::new(Point::new(x, y), Point::new(x + x_unit, y + y_unit))
+                            let (p1, p2) = corners(i, j);
+                            Cell::new(p1, p2, columns, rows)
                         })
                         .collect()
                 })
-                .collect(),
+                .collect();
         }
     }
 
+    // if we're fitting the terminal and it's changed size, catch up with it
+    fn refit(&mut self) {
+        let resizes = terminal::resizes();
+        if self.fit_terminal && resizes != self.resizes {
+            self.resizes = resizes;
+            if let Some((cell_width, cell_height)) =
+                terminal_fit(self.print_timing, self.inline_rows)
+            {
+                self.resize(cell_width, cell_height);
+            }
+        }
+    }
+
+    /// The keys that were pressed since the last frame, oldest first. Always empty unless
+    /// [`GridConfig::keyboard`] is on (and you're drawing with [`draw`]).
+    ///
+    /// ```no_run
+    /// use just_asc::{Control, Draw, Key};
+    ///
+    /// let mut x = 50.;
+    /// just_asc::draw(
+    ///     just_asc::GridConfig {
+    ///         keyboard: true,
+    ///         ..just_asc::DEFAULT_CONFIG
+    ///     },
+    ///     |grid: &mut just_asc::Grid, _: just_asc::Frame| {
+    ///         for key in grid.keys() {
+    ///             match key {
+    ///                 Key::Left => x -= 5.,
+    ///                 Key::Right => x += 5.,
+    ///                 Key::Char('q') => return Control::Stop,
+    ///                 _ => {}
+    ///             }
+    ///         }
+    ///         grid.circle(x, 50., 10.);
+    ///         Control::Continue
+    ///     },
+    /// );
+    /// ```
+    pub fn keys(&self) -> &[Key] {
+        &self.keys
+    }
+
+    /// The part of the world the grid is looking at (see [`Grid::set_viewport`]).
+    pub fn viewport(&self) -> Viewport {
+        self.viewport
+    }
+
+    /// Points the grid at a different part of the world, which is how you pan and zoom around.
+    /// This moves every cell on the grid, so it wipes anything you've already drawn this frame: do
+    /// it before you draw.
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let drawing = just_asc::frames(
+    ///     just_asc::GridConfig {
+    ///         cell_width: 8,
+    ///         cell_height: 1,
+    ///         ..just_asc::DEFAULT_CONFIG
+    ///     },
+    ///     |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+    ///         // follow the circle as it rolls to the right
+    ///         let x = 50. + frame.number as f64 * 25.;
+    ///         grid.set_viewport(just_asc::Viewport::new(x - 50., 0., x + 50., 100.));
+    ///         grid.fill_circle(x, 50., 10.);
+    ///         grid.fill_circle(100., 50., 10.); // a landmark, as we go past it
+    ///     },
+    /// );
+    ///
+    /// let rendered: Vec<String> = drawing.take(3).map(|(_, frame)| frame).collect();
+    /// assert_eq!(rendered, vec!["   ##  #\n", "   #### \n", "   ##   \n"]);
+    /// ```
+    pub fn set_viewport(&mut self, viewport: Viewport) {
+        self.viewport = viewport;
+        let (cell_width, cell_height) = (self.grid.first().map_or(0, Vec::len), self.grid.len());
+        self.resize(cell_width, cell_height);
+    }
+
+    /// The part of the world you can actually see. With [`Aspect::Stretch`] (the default) that's
+    /// exactly your [`Viewport`], but with [`Aspect::Fit`] or [`Aspect::Fill`] it depends on the
+    /// shape of the grid, so check here if you want your drawing to reach the edges.
+    ///
+    /// ```
+    /// use just_asc::{Aspect, Viewport};
+    ///
+    /// let grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///     cell_width: 40,
+    ///     cell_height: 10,
+    ///     aspect: Aspect::Fill,
+    ///     ..just_asc::DEFAULT_CONFIG
+    /// });
+    ///
+    /// // 40 characters across, and 10 (twice as tall) down: the top and bottom get cut off
+    /// assert_eq!(grid.extents(), Viewport::new(0., 25., 100., 75.));
+    /// ```
+    pub fn extents(&self) -> Viewport {
+        self.extents
+    }
+
     /// Creates a [`Transform`] from a Grid. Transforms provide a nice structure for making weird
     /// (temporary) transformations to your grid: just throw it out when you're done!
-    pub fn transform(&mut self) -> Transform {
+    pub fn transform(&mut self) -> Transform<'_> {
         Transform::from(self)
     }
 
@@ -444,58 +1838,533 @@ impl Grid {
     /// scope of a Transform nice and clear.
     pub fn with_transform<F>(&mut self, f: F)
     where
-        F: Fn(Transform) -> (),
+        F: FnOnce(Transform),
     {
         f(Transform::from(self))
     }
 
+    fn render_bezier(&mut self, bezier: &Bezier) {
+        let (top_left, bottom_right) = bezier.bounds();
+        for j in self.rows_between(top_left.y, bottom_right.y) {
+            self.each_cell_between_mut(j, top_left.x, bottom_right.x, |cell| {
+                cell.render(|r| r.overlaps_bezier(bezier))
+            });
+        }
+    }
+
+    // the top and bottom edges of a row of cells
+    fn row_bounds(&self, j: usize) -> (f64, f64) {
+        self.y_axis.bounds(j)
+    }
+
+    // the rows of cells that something between these two y values could touch
+    fn rows_between(&self, y1: f64, y2: f64) -> Range<usize> {
+        self.y_axis.cells_between(y1, y2)
+    }
+
+    // runs `f` on the cells in row `j` that something between these two x values could touch,
+    // coloring in any that it says it filled
+    fn each_cell_between_mut<F>(&mut self, j: usize, x1: f64, x2: f64, f: F)
+    where
+        F: Fn(&mut Cell) -> bool,
+    {
+        let color = self.color;
+        let columns = self.x_axis.cells_between(x1, x2);
+        for cell in self.grid[j][columns].iter_mut() {
+            if f(cell) {
+                cell.color = color;
+            }
+        }
+    }
+
     fn each_cell_mut<F>(&mut self, f: F)
     where
-        F: Fn(&mut Cell) -> (),
+        F: Fn(&mut Cell),
     {
         self.grid
             .iter_mut()
-            .for_each(|row| row.iter_mut().for_each(|cell| f(cell)));
+            .for_each(|row| row.iter_mut().for_each(&f));
     }
 
     fn clear(&mut self) {
         self.each_cell_mut(|cell| {
-            cell.quads_filled[0] = false;
-            cell.quads_filled[1] = false;
-            cell.quads_filled[2] = false;
-            cell.quads_filled[3] = false;
+            cell.filled = 0;
+            cell.color = Color::Default;
         });
+        self.color = Color::Default;
+    }
+
+    /// Writes the current drawing to anything that implements [`Write`]: a file, a socket, a
+    /// `Vec<u8>`, you name it. Each row of cells is followed by a newline. (Any colors are written
+    /// as escape codes: for plain text, there's `write!(writer, "{:#}", grid)`. See
+    /// [`Display`](#impl-Display-for-Grid).)
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// grid.circle(50., 50., 25.);
+    ///
+    /// let mut out: Vec<u8> = Vec::new();
+    /// grid.render_to(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), grid.to_string());
+    /// ```
+    pub fn render_to<W: Write>(&self, writer: &mut W) -> io::Result<()> {
+        write!(writer, "{}", self)
+    }
+
+    /// Like [`Grid::render_to`], but for a terminal that's still showing the last frame you sent
+    /// it: instead of the whole drawing, it writes cursor movements and just the cells that
+    /// changed. If most of the grid changed, it's cheaper to repaint the lot, so it does that
+    /// instead. The first time round (or if the grid's changed size since last time) it wipes
+    /// the screen and starts from scratch.
+    ///
+    /// Either way it leaves the cursor on the line below the grid, and returns how many bytes it
+    /// wrote, so you can keep an eye on how much you're sending down the wire.
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::DEFAULT_CONFIG);
+    /// let mut out: Vec<u8> = Vec::new();
+    ///
+    /// grid.circle(50., 50., 25.);
+    /// let first = grid.render_changes_to(&mut out).unwrap();
+    /// assert!(first > 72 * 36);
+    ///
+    /// // same again: nothing to redraw, just the cursor to put back
+    /// assert_eq!(grid.render_changes_to(&mut out).unwrap(), "\x1b[37;1H".len());
+    ///
+    /// // a short line along the top: a few cells, so a lot cheaper than a repaint
+    /// grid.line(0., 0., 10., 0.);
+    /// assert!(grid.render_changes_to(&mut out).unwrap() < first / 50);
+    /// ```
+    pub fn render_changes_to<W: Write>(&mut self, writer: &mut W) -> io::Result<usize> {
+        let shown = self.shown();
+        let same_size = self.showing(&shown);
+        let cells: usize = shown.iter().map(Vec::len).sum();
+        let changed = if same_size {
+            self.screen
+                .iter()
+                .flatten()
+                .zip(shown.iter().flatten())
+                .filter(|(a, b)| a != b)
+                .count()
+        } else {
+            cells
+        };
+
+        // build the whole frame up first, so it goes out in one write
+        let mut out: Vec<u8> = Vec::new();
+        if !same_size {
+            write!(out, "{esc}[2J{esc}[1;1H{}", self, esc = 27 as char)?;
+        } else if changed * 2 > cells {
+            write!(out, "{}[1;1H{}", 27 as char, self)?;
+        } else {
+            let mut color = Color::Default;
+            // where the next character we write will land
+            let mut cursor = None;
+            for (j, (old, new)) in self.screen.iter().zip(&shown).enumerate() {
+                for (i, (old, &(glyph, glyph_color))) in old.iter().zip(new).enumerate() {
+                    if *old == (glyph, glyph_color) {
+                        continue;
+                    }
+                    if cursor != Some((j, i)) {
+                        write!(out, "{}[{};{}H", 27 as char, j + 1, i + 1)?;
+                    }
+                    if glyph_color != color {
+                        color = glyph_color;
+                        write!(out, "{}", color)?;
+                    }
+                    write!(out, "{}", glyph)?;
+                    cursor = Some((j, i + 1));
+                }
+            }
+            if color != Color::Default {
+                write!(out, "{}", Color::Default)?;
+            }
+            write!(out, "{}[{};1H", 27 as char, shown.len() + 1)?;
+        }
+
+        writer.write_all(&out)?;
+        self.screen = shown;
+        Ok(out.len())
+    }
+
+    /// Like [`Grid::render_changes_to`], but for a grid drawn wherever the cursor happened to be,
+    /// rather than at the top of the screen (see [`GridConfig::inline`]). The first time round it
+    /// goes back to the start of the line the cursor's on (so every frame lines up, even if
+    /// something was already printed there) and writes out the whole grid, which leaves the
+    /// cursor on the line below it. After that, everything's relative to there: it moves the
+    /// cursor up to each row that changed, redraws the row, and comes back down.
+    ///
+    /// ```
+    /// use just_asc::Draw;
+    ///
+    /// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+    ///     cell_width: 8,
+    ///     cell_height: 3,
+    ///     ..just_asc::DEFAULT_CONFIG
+    /// });
+    /// let mut out: Vec<u8> = Vec::new();
+    ///
+    /// grid.line(0., 50., 100., 50.);
+    /// grid.render_inline_to(&mut out).unwrap();
+    /// assert_eq!(String::from_utf8(out).unwrap(), format!("\r{}", grid));
+    ///
+    /// // only the top row changes: up three lines, redraw it, and back down
+    /// let mut out: Vec<u8> = Vec::new();
+    /// grid.line(0., 0., 100., 0.);
+    /// grid.render_inline_to(&mut out).unwrap();
+    ///
+    /// let top = grid.to_string().lines().next().unwrap().to_string();
+    /// assert_eq!(
+    ///     String::from_utf8(out).unwrap(),
+    ///     format!("\x1b[3A\r{}\x1b[3B\r", top)
+    /// );
+    /// ```
+    pub fn render_inline_to<W: Write>(&mut self, writer: &mut W) -> io::Result<usize> {
+        let shown = self.shown();
+        let same_size = self.showing(&shown);
+
+        let mut out: Vec<u8> = Vec::new();
+        if !same_size {
+            // the newlines at the end of each row make room for the grid the first time round.
+            // Later frames are drawn from the start of each line, so this one is too.
+            self.erase_inline(&mut out)?;
+            write!(out, "\r{}", self)?;
+        } else {
+            let rows = shown.len();
+            // which row the cursor's on, counting the line below the grid as `rows`
+            let mut line = rows;
+            for (j, row) in self.grid.iter().enumerate() {
+                if self.screen[j] == shown[j] {
+                    continue;
+                }
+                write!(out, "{}", cursor_to(line, j))?;
+                write!(out, "\r{}", Row(row, &self.tileset, true))?;
+                line = j;
+            }
+            if line != rows {
+                write!(out, "{}\r", cursor_to(line, rows))?;
+            }
+        }
+
+        writer.write_all(&out)?;
+        self.screen = shown;
+        Ok(out.len())
+    }
+
+    // rubs out an inline grid, leaving the cursor where it started
+    fn erase_inline<W: Write>(&mut self, writer: &mut W) -> io::Result<()> {
+        if !self.screen.is_empty() {
+            let rows = self.screen.len();
+            write!(writer, "{}\r{}[J", cursor_to(rows, 0), 27 as char)?;
+            self.screen.clear();
+        }
+        Ok(())
+    }
+
+    // whether the screen is showing a grid the same size as `shown`
+    fn showing(&self, shown: &[Vec<(char, Color)>]) -> bool {
+        self.screen.len() == shown.len()
+            && self
+                .screen
+                .iter()
+                .zip(shown)
+                .all(|(a, b)| a.len() == b.len())
     }
 
-    fn print(&self) {
-        self.grid.iter().for_each(|row| {
-            println!(
-                "{}",
+    // what each cell looks like on the terminal
+    fn shown(&self) -> Vec<Vec<(char, Color)>> {
+        self.grid
+            .iter()
+            .map(|row| {
                 row.iter()
-                    .map(|cell| cell.print(&self.tileset))
-                    .collect::<String>()
-            )
-        })
+                    .map(|cell| match cell.filled {
+                        // blank cells look the same in any color
+                        0 => (cell.print(&self.tileset), Color::Default),
+                        _ => (cell.print(&self.tileset), cell.color),
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    // sends the changes since the last frame to the terminal, returning the bytes written
+    fn print(&mut self) -> usize {
+        // after Ctrl-Z, whatever we'd drawn is long gone, so start again from scratch
+        let resumes = terminal::resumes();
+        if resumes != self.resumes {
+            self.resumes = resumes;
+            self.screen.clear();
+        }
+        let mut stdout = io::stdout().lock();
+        let written = match self.inline_rows {
+            Some(_) => self.render_inline_to(&mut stdout),
+            None => self.render_changes_to(&mut stdout),
+        }
+        .expect("failed printing to stdout");
+        stdout.flush().expect("failed printing to stdout");
+        written
+    }
+}
+
+/// Grids display as the text they'd print, so `grid.to_string()` gets you a frame as a `String`.
+///
+/// ```
+/// use just_asc::Draw;
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 4,
+///     cell_height: 2,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.line(0., 0., 100., 100.);
+///
+/// assert_eq!(grid.to_string(), "\"_  \n  \"_\n");
+/// ```
+///
+/// If you've drawn in [color](Draw::set_color), the escape codes for the colors come along too.
+/// To leave them out (say, for a log file), format it with `{:#}`:
+///
+/// ```
+/// use just_asc::{Color, Draw};
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 4,
+///     cell_height: 2,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.set_color(Color::RED);
+/// grid.line(0., 0., 100., 100.);
+///
+/// assert_eq!(grid.to_string(), "\x1b[31m\"_  \x1b[39m\n  \x1b[31m\"_\x1b[39m\n");
+/// assert_eq!(format!("{:#}", grid), "\"_  \n  \"_\n");
+/// ```
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let colored = !f.alternate();
+        for row in &self.grid {
+            writeln!(f, "{}", Row(row, &self.tileset, colored))?;
+        }
+        Ok(())
     }
 }
 
-fn sleep_less(subtract_amount: usize, millis: usize) {
-    if subtract_amount >= millis {
-        return;
+// a row of cells, as it prints (without the newline), with or without its colors
+struct Row<'a>(&'a [Cell], &'a Tileset, bool);
+
+impl<'a> fmt::Display for Row<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // only switch colors when we have to: blank cells look the same in any color
+        let mut color = Color::Default;
+        for cell in self.0 {
+            if self.2 && cell.filled != 0 && cell.color != color {
+                color = cell.color;
+                write!(f, "{}", color)?;
+            }
+            write!(f, "{}", cell.print(self.1))?;
+        }
+        if color != Color::Default {
+            write!(f, "{}", Color::Default)?;
+        }
+        Ok(())
+    }
+}
+
+// the escape code that moves the cursor up or down from one row to another (in the same column)
+fn cursor_to(from: usize, to: usize) -> String {
+    match from.cmp(&to) {
+        std::cmp::Ordering::Greater => format!("{}[{}A", 27 as char, from - to),
+        std::cmp::Ordering::Less => format!("{}[{}B", 27 as char, to - from),
+        std::cmp::Ordering::Equal => String::new(),
+    }
+}
+
+/// What [`draw`] (and [`frames`]) tell your drawing closure about the frame it's drawing.
+///
+/// Frames don't always come exactly on time, and a busy frame can push the next one back, so for
+/// motion that runs at the same speed however fast frames are drawn, go by time rather than
+/// [`number`](Frame::number):
+///
+/// ```
+/// use crate::just_asc::Draw;
+/// use std::time::Duration;
+///
+/// let mut times = Vec::new();
+/// let rendered: Vec<(usize, String)> = just_asc::frames(
+///     just_asc::DEFAULT_CONFIG,
+///     |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+///         // across the screen in 5 seconds, at any framerate
+///         let x = frame.elapsed.as_secs_f64() * 20.;
+///         grid.circle(x, 50., 10.);
+///         times.push(frame.elapsed);
+///     },
+/// )
+/// .take(100)
+/// .collect();
+///
+/// // at the default 20 frames a second, frame 50 comes 2.5 seconds in, halfway across
+/// assert_eq!(times[50], Duration::from_millis(2500));
+/// assert_ne!(rendered[0].1, rendered[50].1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Frame {
+    /// Which frame this is, counting from `0`.
+    pub number: usize,
+
+    /// How long it's been since the animation started.
+    pub elapsed: Duration,
+
+    /// How long it's been since the last frame (zero for the first one). Handy for moving things
+    /// along a little at a time: multiply their speed by this (in seconds).
+    pub delta: Duration,
+
+    /// The framerate we're aiming for (see [`GridConfig::max_framerate`]).
+    pub target_fps: usize,
+
+    /// How many frames have been skipped so far, because drawing took longer than a frame's worth
+    /// of time. If this keeps going up, try drawing less, or asking for a lower framerate.
+    pub dropped: usize,
+}
+
+/// What your drawing closure can tell [`draw`] (or [`frames`]) to do next, by returning it.
+/// Closures that don't return anything just [`Continue`](Control::Continue).
+///
+/// ```
+/// use just_asc::{Control, Draw};
+///
+/// let drawn = just_asc::frames(
+///     just_asc::DEFAULT_CONFIG,
+///     |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+///         grid.circle(50., 50., frame.number as f64 * 10.);
+///         if frame.number == 4 {
+///             Control::Stop
+///         } else {
+///             Control::Continue
+///         }
+///     },
+/// )
+/// .count();
+///
+/// assert_eq!(drawn, 5);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    /// Carry on to the next frame, as usual.
+    #[default]
+    Continue,
+
+    /// That's it: show this frame, and then stop. [`draw`] puts the terminal back how it was and
+    /// returns.
+    Stop,
+
+    /// Hold on to this frame for a while before carrying on. The clock stops while it's paused,
+    /// so the next frame's [`elapsed`](Frame::elapsed) and [`delta`](Frame::delta) pick up where
+    /// they left off.
+    Pause(Duration),
+
+    /// Carry on at a different framerate (at least `1`, and at most one a nanosecond) from now
+    /// on.
+    SetFramerate(usize),
+}
+
+impl From<()> for Control {
+    fn from(_: ()) -> Self {
+        Control::Continue
+    }
+}
+
+// a framerate we can split a second up by: at least one frame a second, and at most one a
+// nanosecond (any faster, and there'd be no time between them at all)
+fn framerate(target_fps: usize) -> u32 {
+    target_fps.clamp(1, 1_000_000_000) as u32
+}
+
+// keeps frames coming at a steady rate. Each frame has a deadline, one period after the last one's
+// (rather than one period after the last frame _finished_), so the time spent drawing doesn't
+// slow the animation down.
+struct Pacer {
+    target_fps: u32,
+    period: Duration,
+    start: Instant,
+    last: Instant,
+    deadline: Instant,
+    number: usize,
+    dropped: usize,
+}
+
+impl Pacer {
+    fn new(target_fps: u32) -> Pacer {
+        let now = Instant::now();
+        Pacer {
+            target_fps,
+            period: Duration::from_secs(1) / target_fps,
+            start: now,
+            last: now,
+            deadline: now,
+            number: 0,
+            dropped: 0,
+        }
+    }
+
+    // the frame we're about to draw
+    fn frame(&mut self) -> Frame {
+        let now = Instant::now();
+        let frame = Frame {
+            number: self.number,
+            elapsed: now - self.start,
+            delta: now - self.last,
+            target_fps: self.target_fps as usize,
+            dropped: self.dropped,
+        };
+        self.number += 1;
+        self.last = now;
+        frame
+    }
+
+    fn set_framerate(&mut self, target_fps: usize) {
+        self.target_fps = framerate(target_fps);
+        self.period = Duration::from_secs(1) / self.target_fps;
+    }
+
+    // waits for a while, without letting the time count towards the animation. The waiting itself
+    // is up to `sleep` (usually `thread::sleep`), so it can be cut short.
+    fn pause<S: FnOnce(Duration)>(&mut self, duration: Duration, sleep: S) {
+        let before = Instant::now();
+        sleep(duration);
+        let paused = before.elapsed();
+        self.start += paused;
+        self.last += paused;
+        self.deadline += paused;
+    }
+
+    // waits for the next frame's deadline. If we've already missed it (and maybe a few more after
+    // it), there's no catching up: skip ahead, and keep count of what we skipped.
+    fn wait<S: FnOnce(Duration)>(&mut self, sleep: S) {
+        self.deadline += self.period;
+        let now = Instant::now();
+        if now < self.deadline {
+            sleep(self.deadline - now);
+        } else {
+            let missed = (now - self.deadline).as_nanos() / self.period.as_nanos();
+            let missed = missed.min(u32::MAX as u128) as u32;
+            self.dropped = self.dropped.saturating_add(missed as usize);
+            self.deadline += self.period * missed;
+        }
     }
-    std::thread::sleep(std::time::Duration::from_millis(
-        (millis - subtract_amount) as u64,
-    ));
 }
 
 const TIMING_SIZE: usize = 50;
 
-fn print_average(frame: usize, arr: &[u128; TIMING_SIZE]) {
+fn print_average(frame: usize, arr: &[u128; TIMING_SIZE], bytes: &[usize; TIMING_SIZE]) {
     if frame > TIMING_SIZE {
         println!(
-            "average time to paint (over {} frames): {}ms                       ",
+            "average time to paint (over {} frames): {}ms, {} bytes                       ",
             TIMING_SIZE,
-            arr.iter().sum::<u128>() / TIMING_SIZE as u128
+            arr.iter().sum::<u128>() / TIMING_SIZE as u128,
+            bytes.iter().sum::<usize>() / TIMING_SIZE
         );
     } else {
         println!(
@@ -513,13 +2382,14 @@ fn print_average(frame: usize, arr: &[u128; TIMING_SIZE]) {
 /// 2. a drawing closure, which will receive a fresh [`Grid`]
 pub fn once<F>(config: GridConfig, draw_fn: F)
 where
-    F: Fn(&mut Grid) -> (),
+    F: FnOnce(&mut Grid),
 {
     let mut grid = Grid::new(config);
     let now = std::time::Instant::now();
 
     draw_fn(&mut grid);
-    grid.print();
+    grid.render_to(&mut io::stdout().lock())
+        .expect("failed printing to stdout");
 
     let spent = now.elapsed().as_millis();
     if grid.print_timing {
@@ -529,32 +2399,218 @@ where
 
 /// Our core animation function.
 ///
-/// This kicks off an unending drawing, taking two arguments:
+/// This kicks off a drawing that keeps going until you tell it to stop, taking two arguments:
 ///
 /// 1. a [`GridConfig`]
 /// 2. a drawing closure, which will receive a fresh [`Grid`] (drawings are erased every frame),
-///    and the current frame count.
-pub fn draw<F>(config: GridConfig, draw_fn: F)
+///    and a [`Frame`], with the frame count and how much time has passed. It's free to hang on to
+///    state between frames (positions, velocities, random number generators) and change it as it
+///    goes. It can return a [`Control`] to stop, pause or change the framerate, or nothing at all
+///    to keep going.
+///
+/// Once the closure says to [`Stop`](Control::Stop), `draw` hands back the last [`Frame`] (so you
+/// can see how many frames were drawn, or dropped, and how long it all took). Anything the closure
+/// borrowed is yours again, just as it left it.
+///
+/// Frames are drawn at (up to) [`GridConfig::max_framerate`], on a steady beat: if one takes too
+/// long, the next comes sooner, and if we fall a whole frame behind, we skip ahead (see
+/// [`Frame::dropped`]).
+///
+/// The drawing happens on the terminal's alternate screen with the cursor hidden (unless it's
+/// [`inline`](GridConfig::inline)), and both are put back when your program panics or gets a
+/// Ctrl-C (see [`TerminalGuard`]).
+///
+/// If you want the frames without the terminal (or want to stop after a while), see [`frames`].
+pub fn draw<F, R>(config: GridConfig, mut draw_fn: F) -> Frame
 where
-    F: Fn(&mut Grid, usize) -> (),
+    F: FnMut(&mut Grid, Frame) -> R,
+    R: Into<Control>,
 {
     let mut timing: [u128; TIMING_SIZE] = [4; TIMING_SIZE];
+    let mut bytes: [usize; TIMING_SIZE] = [0; TIMING_SIZE];
+    let _watch = config.fit_terminal.then(terminal::watch_resize);
     let mut grid = Grid::new(config);
-    print!("{esc}[2J{esc}[1;1H", esc = 27 as char);
-    for frame in 0.. {
-        let now = std::time::Instant::now();
+    let mut pacer = Pacer::new(grid.max_framerate);
+    let inline = grid.inline_rows.is_some();
+    let _guard = if inline {
+        TerminalGuard::inline()
+    } else {
+        TerminalGuard::new()
+    }
+    .expect("failed printing to stdout");
+    // no terminal, no keys
+    let mut keyboard = if grid.keyboard {
+        Keyboard::new().ok()
+    } else {
+        None
+    };
+    loop {
+        let frame = pacer.frame();
+        if let Some(keyboard) = &mut keyboard {
+            grid.keys = keyboard.keys();
+        }
+        let now = Instant::now();
 
-        draw_fn(&mut grid, frame);
-        print!("{}[1;1H", 27 as char);
-        grid.print();
+        grid.refit();
+        let control = draw_fn(&mut grid, frame).into();
+        let written = grid.print();
         grid.clear();
 
         let spent = now.elapsed().as_millis();
         if grid.print_timing {
-            timing[frame % TIMING_SIZE] = spent;
-            print_average(frame, &timing);
+            timing[frame.number % TIMING_SIZE] = spent;
+            bytes[frame.number % TIMING_SIZE] = written;
+            if inline {
+                // the timing has to stay on one line (no wrapping), so we know where we are...
+                print!("{}[?7l", 27 as char);
+                print_average(frame.number, &timing, &bytes);
+                print!("{}[?7h", 27 as char);
+                // ...and there's no jumping back to the top, so step back up to just below the grid
+                if control != Control::Stop {
+                    print!("{}[1A", 27 as char);
+                }
+            } else {
+                print_average(frame.number, &timing, &bytes);
+            }
+        }
+        if !inline {
+            print!("                         ");
+        }
+
+        match control {
+            Control::Continue => {}
+            Control::Stop => return frame,
+            Control::Pause(duration) => pacer.pause(duration, std::thread::sleep),
+            Control::SetFramerate(target_fps) => pacer.set_framerate(target_fps),
+        }
+        pacer.wait(std::thread::sleep);
+    }
+}
+
+/// An iterator over the frames of an animation, rendered to `String`s. Made with [`frames`].
+pub struct Frames<F> {
+    grid: Grid,
+    draw_fn: F,
+    frame: usize,
+    target_fps: u32,
+    elapsed: Duration,
+    stopped: bool,
+}
+
+impl<F, R> Iterator for Frames<F>
+where
+    F: FnMut(&mut Grid, Frame) -> R,
+    R: Into<Control>,
+{
+    type Item = (usize, String);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.stopped {
+            return None;
+        }
+        let number = self.frame;
+        self.frame += 1;
+
+        // nobody's waiting between these, so pretend every frame came right on time
+        let delta = match number {
+            0 => Duration::ZERO,
+            _ => Duration::from_secs(1) / self.target_fps,
+        };
+        self.elapsed += delta;
+        let frame = Frame {
+            number,
+            elapsed: self.elapsed,
+            delta,
+            target_fps: self.target_fps as usize,
+            dropped: 0,
+        };
+
+        let control = (self.draw_fn)(&mut self.grid, frame).into();
+        let rendered = self.grid.to_string();
+        self.grid.clear();
+
+        // there's no real time to pause, so pausing doesn't do anything here
+        match control {
+            Control::Continue | Control::Pause(_) => {}
+            Control::Stop => self.stopped = true,
+            Control::SetFramerate(target_fps) => self.target_fps = framerate(target_fps),
         }
-        print!("                         ");
-        sleep_less(spent as usize, 1000 / grid.max_framerate);
+        Some((number, rendered))
+    }
+}
+
+/// A "headless" version of [`draw`]: rather than printing to your terminal forever, this gives
+/// you an iterator of `(frame, rendered)` pairs. Nothing is printed and nobody sleeps, so it's up
+/// to you where frames go and how many you take.
+///
+/// Takes the same arguments as [`draw`]:
+///
+/// 1. a [`GridConfig`]
+/// 2. a drawing closure, which will receive a fresh [`Grid`] (drawings are erased every frame),
+///    and a [`Frame`]. The time in it is make-believe: every frame is exactly one
+///    [`max_framerate`](GridConfig::max_framerate)'th of a second after the last (or whatever
+///    your closure [sets it to](Control::SetFramerate)), so you get the same frames every time.
+///    Once the closure says to [`Stop`](Control::Stop), that's the last frame you get.
+///
+/// ```
+/// use crate::just_asc::Draw;
+///
+/// let rendered: Vec<(usize, String)> = just_asc::frames(
+///     just_asc::DEFAULT_CONFIG,
+///     |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+///         grid.circle(50., 50., frame.number as f64 * 10.);
+///     },
+/// )
+/// .take(5)
+/// .collect();
+///
+/// assert_eq!(rendered.len(), 5);
+/// assert_eq!(rendered[3].0, 3);
+/// assert_ne!(rendered[3].1, rendered[4].1);
+/// ```
+///
+/// Like [`draw`], the closure can keep its own state from one frame to the next:
+///
+/// ```
+/// use crate::just_asc::Draw;
+///
+/// // a ball, bouncing back and forth
+/// let (mut x, mut speed) = (15., 30.);
+/// let rendered: Vec<String> = just_asc::frames(
+///     just_asc::GridConfig {
+///         cell_width: 10,
+///         cell_height: 1,
+///         ..just_asc::DEFAULT_CONFIG
+///     },
+///     |grid: &mut just_asc::Grid, _| {
+///         grid.fill_circle(x, 50., 4.);
+///         if !(0. ..=100.).contains(&(x + speed)) {
+///             speed = -speed;
+///         }
+///         x += speed;
+///     },
+/// )
+/// .map(|(_, frame)| frame)
+/// .take(5)
+/// .collect();
+///
+/// assert_eq!(
+///     rendered,
+///     vec![" #        \n", "    #     \n", "       #  \n", "    #     \n", " #        \n"]
+/// );
+/// ```
+pub fn frames<F, R>(config: GridConfig, draw_fn: F) -> Frames<F>
+where
+    F: FnMut(&mut Grid, Frame) -> R,
+    R: Into<Control>,
+{
+    let grid = Grid::new(config);
+    Frames {
+        target_fps: grid.max_framerate,
+        grid,
+        draw_fn,
+        frame: 0,
+        elapsed: Duration::ZERO,
+        stopped: false,
     }
 }
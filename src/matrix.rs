@@ -0,0 +1,113 @@
+use std::ops::Mul;
+
+/// A 2D affine transformation: any combination of moving, rotating, scaling, skewing and
+/// mirroring. You'll mostly build these up a step at a time on a
+/// [`Transform`](crate::Transform), but if you've got one from somewhere else (or want to reuse
+/// one), [`Transform::apply`](crate::Transform::apply) takes them whole.
+///
+/// The six numbers are laid out like they are in SVG and the HTML canvas, so a point `(x, y)`
+/// ends up at `(a * x + c * y + e, b * x + d * y + f)`.
+///
+/// Multiplying two matrices makes one that does both, right-hand side first:
+///
+/// ```
+/// use just_asc::Matrix;
+/// use std::f64::consts::PI;
+///
+/// // scoot over, _then_ turn a quarter of the way around
+/// let matrix = Matrix::rotation(PI / 2.) * Matrix::translation(10., 0.);
+/// let (x, y) = matrix.apply(0., 0.);
+/// assert!(x.abs() < 1e-9 && (y - 10.).abs() < 1e-9);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Matrix {
+    a: f64,
+    b: f64,
+    c: f64,
+    d: f64,
+    e: f64,
+    f: f64,
+}
+
+impl Matrix {
+    /// The matrix that leaves everything where it is.
+    pub const IDENTITY: Matrix = Matrix::new(1., 0., 0., 1., 0., 0.);
+
+    /// A matrix from its six numbers (see [`Matrix`] for what goes where).
+    pub const fn new(a: f64, b: f64, c: f64, d: f64, e: f64, f: f64) -> Matrix {
+        Matrix { a, b, c, d, e, f }
+    }
+
+    /// Moves things `x` across and `y` down.
+    pub fn translation(x: f64, y: f64) -> Matrix {
+        Matrix::new(1., 0., 0., 1., x, y)
+    }
+
+    /// Rotates things around the origin (in radians).
+    pub fn rotation(radians: f64) -> Matrix {
+        let (sin, cos) = radians.sin_cos();
+        Matrix::new(cos, sin, -sin, cos, 0., 0.)
+    }
+
+    /// Stretches things `x` times as wide and `y` times as tall, from the origin. A negative scale
+    /// mirrors things across that axis.
+    pub fn scaling(x: f64, y: f64) -> Matrix {
+        Matrix::new(x, 0., 0., y, 0., 0.)
+    }
+
+    /// Leans things over: vertical lines tip by `x` radians, and horizontal ones by `y` radians.
+    pub fn skewing(x: f64, y: f64) -> Matrix {
+        Matrix::new(1., y.tan(), x.tan(), 1., 0., 0.)
+    }
+
+    /// Where the point `(x, y)` ends up.
+    pub fn apply(&self, x: f64, y: f64) -> (f64, f64) {
+        (
+            self.a * x + self.c * y + self.e,
+            self.b * x + self.d * y + self.f,
+        )
+    }
+
+    // just the rotating/scaling/skewing part, without any moving
+    pub(crate) fn linear(&self) -> Matrix {
+        Matrix::new(self.a, self.b, self.c, self.d, 0., 0.)
+    }
+
+    // splits the linear part into a rotation, then a scale, then another rotation, so it's
+    // `rotation(after) * scaling(x, y) * rotation(before)`, returned as `(after, x, y, before)`.
+    // `x` is always the bigger scale, and `y` comes out negative if the matrix mirrors things.
+    // (This is the singular value decomposition, worked out for 2x2 by Jim Blinn.)
+    pub(crate) fn decompose(&self) -> (f64, f64, f64, f64) {
+        let e = (self.a + self.d) / 2.;
+        let f = (self.a - self.d) / 2.;
+        let g = (self.b + self.c) / 2.;
+        let h = (self.b - self.c) / 2.;
+
+        let q = e.hypot(h);
+        let r = f.hypot(g);
+        let (a1, a2) = (g.atan2(f), h.atan2(e));
+
+        ((a2 + a1) / 2., q + r, q - r, (a2 - a1) / 2.)
+    }
+}
+
+impl Default for Matrix {
+    fn default() -> Self {
+        Matrix::IDENTITY
+    }
+}
+
+impl Mul for Matrix {
+    type Output = Matrix;
+
+    fn mul(self, rhs: Matrix) -> Matrix {
+        Matrix::new(
+            self.a * rhs.a + self.c * rhs.b,
+            self.b * rhs.a + self.d * rhs.b,
+            self.a * rhs.c + self.c * rhs.d,
+            self.b * rhs.c + self.d * rhs.d,
+            self.a * rhs.e + self.c * rhs.f + self.e,
+            self.b * rhs.e + self.d * rhs.f + self.f,
+        )
+    }
+}
@@ -1,3 +1,5 @@
+use std::f64::consts::PI;
+
 #[derive(Debug, Clone)]
 pub struct Point {
     pub x: f64,
@@ -48,6 +50,21 @@ impl Line {
         }
     }
 
+    // the horizontal extent of the part of this line between two y values
+    pub fn x_span(&self, y1: f64, y2: f64) -> (f64, f64) {
+        let (t1, t2) = if self.dy == 0. {
+            (0., 1.)
+        } else {
+            let t1 = (y1 - self.start.y) / self.dy;
+            let t2 = (y2 - self.start.y) / self.dy;
+            (t1.min(t2).max(0.), t1.max(t2).min(1.))
+        };
+        let x1 = self.start.x + t1 * self.dx;
+        let x2 = self.start.x + t2 * self.dx;
+
+        (x1.min(x2), x1.max(x2))
+    }
+
     fn rotate(&self, axis: &Point, keel: f64) -> Self {
         let start = self.start.rotate(axis, keel);
         let end = self.end.rotate(axis, keel);
@@ -55,26 +72,19 @@ impl Line {
         Line::new(start, end)
     }
 
-    fn intersects_line(&self, other: &Line) -> bool {
-        let Point { x: a, y: b } = self.start;
-        let Point { x: r, y: s } = other.end;
-        let det = (self.dx) * (other.dy) - (other.dx) * (self.dy);
-        if det == 0. {
-            return false;
-        }
-        let lambda = (other.dy * (r - a) - other.dx * (s - b)) / det;
-        let gamma = (self.dx * (s - b) - self.dy * (r - a)) / det;
-
-        0. < lambda && lambda < 1. && 0. < gamma && gamma < 1.
+    fn at(&self, t: f64) -> Point {
+        Point::new(self.start.x + t * self.dx, self.start.y + t * self.dy)
     }
 
-    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+    // the t values where this line (stretched out infinitely in both directions) crosses the
+    // ellipse, if it does
+    fn ellipse_crossings(&self, ellipse: &Ellipse) -> Option<(f64, f64)> {
         let ex = self.start.x - ellipse.center.x;
         let ey = self.start.y - ellipse.center.y;
         let e_dist = (ex.powf(2.) + ey.powf(2.)).sqrt();
 
         if e_dist > self.length + ellipse.max_axis {
-            return false;
+            return None;
         }
 
         let slf = self.rotate(&ellipse.center, ellipse.keel);
@@ -86,14 +96,30 @@ impl Line {
         let c = ax.powf(2.) / ellipse.a_squared + ay.powf(2.) / ellipse.b_squared - 1.;
         let discriminant = b.powf(2.) - 4. * a * c;
         if discriminant < 0. {
-            return false;
+            return None;
         }
 
         let sqrt_discriminant = discriminant.sqrt();
         let t1 = (-b + sqrt_discriminant) / (2. * a);
         let t2 = (-b - sqrt_discriminant) / (2. * a);
 
-        (0. < t1 && t1 < 1.) || (0. < t2 && t2 < 1.)
+        Some((t1, t2))
+    }
+
+    fn intersects_ellipse(&self, ellipse: &Ellipse) -> bool {
+        match self.ellipse_crossings(ellipse) {
+            Some((t1, t2)) => (0. < t1 && t1 < 1.) || (0. < t2 && t2 < 1.),
+            None => false,
+        }
+    }
+
+    fn intersects_arc(&self, arc: &Arc) -> bool {
+        match self.ellipse_crossings(&arc.ellipse) {
+            Some((t1, t2)) => [t1, t2]
+                .iter()
+                .any(|&t| (0. ..=1.).contains(&t) && arc.spans(&self.at(t))),
+            None => false,
+        }
     }
 
     fn intersects_circle(&self, circle: &Circle) -> bool {
@@ -116,6 +142,10 @@ impl Line {
     }
 }
 
+// the shortest stretch of a line (as a fraction of its length) that counts as passing through a
+// rectangle, rather than just touching a corner
+const SLIVER: f64 = 0.000000001;
+
 // a pair of points defining the bounds, with some precomputed values
 #[derive(Debug)]
 pub struct Rectangle {
@@ -128,7 +158,10 @@ pub struct Rectangle {
 }
 
 impl Rectangle {
-    pub fn new(top_left: Point, bottom_right: Point) -> Self {
+    // takes any two opposite corners: a flipped axis can hand us the bottom-left and top-right
+    pub fn new(corner: Point, opposite: Point) -> Self {
+        let top_left = Point::new(corner.x.min(opposite.x), corner.y.min(opposite.y));
+        let bottom_right = Point::new(corner.x.max(opposite.x), corner.y.max(opposite.y));
         let top = Line::new(top_left.clone(), Point::new(bottom_right.x, top_left.y));
         let right = Line::new(Point::new(bottom_right.x, top_left.y), bottom_right.clone());
         let bottom = Line::new(Point::new(top_left.x, bottom_right.y), bottom_right.clone());
@@ -144,18 +177,87 @@ impl Rectangle {
         }
     }
 
+    // whether the rectangle "owns" a point: points on the top and left edges belong to this
+    // rectangle, points on the bottom and right edges belong to its neighbors
+    fn owns(&self, point: &Point) -> bool {
+        self.top_left.x <= point.x
+            && point.x < self.bottom_right.x
+            && self.top_left.y <= point.y
+            && point.y < self.bottom_right.y
+    }
+
+    // the part of a line that's inside the rectangle (edges included), as a range of t values
+    // along it, where 0 is the line's start and 1 is its end
+    fn clip(&self, line: &Line) -> Option<(f64, f64)> {
+        let mut t_in: f64 = 0.;
+        let mut t_out: f64 = 1.;
+        let bounds = [
+            (-line.dx, line.start.x - self.top_left.x),
+            (line.dx, self.bottom_right.x - line.start.x),
+            (-line.dy, line.start.y - self.top_left.y),
+            (line.dy, self.bottom_right.y - line.start.y),
+        ];
+
+        for &(p, q) in bounds.iter() {
+            if p == 0. {
+                if q < 0. {
+                    return None;
+                }
+            } else if p < 0. {
+                t_in = t_in.max(q / p);
+            } else {
+                t_out = t_out.min(q / p);
+            }
+        }
+
+        if t_in > t_out {
+            None
+        } else {
+            Some((t_in, t_out))
+        }
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        self.top_left.x <= point.x
+            && point.x <= self.bottom_right.x
+            && self.top_left.y <= point.y
+            && point.y <= self.bottom_right.y
+    }
+
+    // A line overlaps if either end is in the rectangle, or if some stretch of it passes through.
+    // Lines that only graze a corner don't count, and neither do lines running along the bottom
+    // or right edges: those belong to the neighbors.
     pub fn overlaps_line(&self, line: &Line) -> bool {
-        self.top.intersects_line(line)
-            || self.right.intersects_line(line)
-            || self.bottom.intersects_line(line)
-            || self.left.intersects_line(line)
+        if self.owns(&line.start) || self.owns(&line.end) {
+            return true;
+        }
+
+        match self.clip(line) {
+            Some((t_in, t_out)) if t_out - t_in > SLIVER => {
+                let along_right = line.dx == 0. && line.start.x == self.bottom_right.x;
+                let along_bottom = line.dy == 0. && line.start.y == self.bottom_right.y;
+                !(along_right || along_bottom)
+            }
+            _ => false,
+        }
+    }
+
+    fn center(&self) -> Point {
+        Point::new(
+            (self.top_left.x + self.bottom_right.x) / 2.,
+            (self.top_left.y + self.bottom_right.y) / 2.,
+        )
     }
 
+    // For curves: if one crosses none of the edges, it's either entirely inside the rectangle or
+    // entirely outside of it, so checking any one of its points settles it.
+
     pub fn overlaps_circle(&self, circle: &Circle) -> bool {
         self.top.intersects_circle(circle)
             || self.right.intersects_circle(circle)
             || self.bottom.intersects_circle(circle)
             || self.left.intersects_circle(circle)
+            || self.contains(&circle.vertex())
     }
 
     pub fn overlaps_ellipse(&self, ellipse: &Ellipse) -> bool {
@@ -163,12 +265,73 @@ impl Rectangle {
             || self.right.intersects_ellipse(ellipse)
             || self.bottom.intersects_ellipse(ellipse)
             || self.left.intersects_ellipse(ellipse)
+            || self.contains(&ellipse.vertex())
+    }
+
+    pub fn overlaps_arc(&self, arc: &Arc) -> bool {
+        self.top.intersects_arc(arc)
+            || self.right.intersects_arc(arc)
+            || self.bottom.intersects_arc(arc)
+            || self.left.intersects_arc(arc)
+            || self.contains(&arc.start_point())
+            || self.contains(&arc.end_point())
+    }
+
+    // The edges are all straight up-and-down or side-to-side, so finding where a curve crosses
+    // one means solving for where the curve's x (or y) hits a single value.
+    pub fn overlaps_bezier(&self, bezier: &Bezier) -> bool {
+        let (left, top) = (self.top_left.x, self.top_left.y);
+        let (right, bottom) = (self.bottom_right.x, self.bottom_right.y);
+        let xs = bezier.coefficients(|p| p.x);
+        let ys = bezier.coefficients(|p| p.y);
+        let crosses = |along: &[f64; 4], value: f64, across: &[f64; 4], from: f64, to: f64| {
+            cubic_roots(along[0], along[1], along[2], along[3] - value)
+                .iter()
+                .map(|&t| evaluate(across, t))
+                .any(|n| from <= n && n <= to)
+        };
+
+        crosses(&xs, left, &ys, top, bottom)
+            || crosses(&xs, right, &ys, top, bottom)
+            || crosses(&ys, top, &xs, left, right)
+            || crosses(&ys, bottom, &xs, left, right)
+            || self.contains(&bezier.points[0])
+    }
+
+    // For filled shapes: if the outline doesn't touch the rectangle, then the rectangle is either
+    // entirely inside the shape or entirely outside of it, so again one point settles it.
+
+    pub fn overlaps_filled_circle(&self, circle: &Circle) -> bool {
+        let closest_x = circle
+            .center
+            .x
+            .max(self.top_left.x)
+            .min(self.bottom_right.x);
+        let closest_y = circle
+            .center
+            .y
+            .max(self.top_left.y)
+            .min(self.bottom_right.y);
+        let distance_squared =
+            (closest_x - circle.center.x).powf(2.) + (closest_y - circle.center.y).powf(2.);
+
+        distance_squared < circle.r_squared || self.owns(&circle.center)
+    }
+
+    pub fn overlaps_filled_ellipse(&self, ellipse: &Ellipse) -> bool {
+        self.overlaps_ellipse(ellipse) || ellipse.contains(&self.center())
+    }
+
+    pub fn overlaps_polygon(&self, polygon: &Polygon) -> bool {
+        polygon.edges.iter().any(|edge| self.overlaps_line(edge))
+            || polygon.contains(&self.center())
     }
 }
 
 #[derive(Debug)]
 pub struct Circle {
     center: Point,
+    r: f64,
     r_squared: f64,
 }
 
@@ -176,14 +339,43 @@ impl Circle {
     pub fn new(center: Point, r: f64) -> Circle {
         Circle {
             center,
+            r: r.abs(),
             r_squared: r.powf(2.),
         }
     }
+
+    // a point on the circle
+    fn vertex(&self) -> Point {
+        Point::new(self.center.x + self.r, self.center.y)
+    }
+
+    pub fn y_span(&self) -> (f64, f64) {
+        (self.center.y - self.r, self.center.y + self.r)
+    }
+
+    // the (up to two) horizontal extents of the outline between two y values. Anything between
+    // them is inside the circle, and can't touch the outline.
+    pub fn x_spans(&self, y1: f64, y2: f64) -> [(f64, f64); 2] {
+        let near = if y1 <= self.center.y && self.center.y <= y2 {
+            0.
+        } else {
+            (y1 - self.center.y).abs().min((y2 - self.center.y).abs())
+        };
+        let far = (y1 - self.center.y).abs().max((y2 - self.center.y).abs());
+
+        let outer = (self.r_squared - near.powf(2.)).max(0.).sqrt();
+        let inner = (self.r_squared - far.powf(2.)).max(0.).sqrt();
+        let x = self.center.x;
+
+        [(x - outer, x - inner), (x + inner, x + outer)]
+    }
 }
 
 #[derive(Debug)]
 pub struct Ellipse {
     center: Point,
+    a: f64,
+    b: f64,
     max_axis: f64,
     a_squared: f64,
     b_squared: f64,
@@ -194,10 +386,274 @@ impl Ellipse {
     pub fn new(center: Point, a: f64, b: f64, keel: f64) -> Self {
         Ellipse {
             center,
-            max_axis: a.max(b),
+            a,
+            b,
+            max_axis: a.abs().max(b.abs()),
             a_squared: a.powf(2.),
             b_squared: b.powf(2.),
             keel,
         }
     }
+
+    // a point on the ellipse: the end of its x axis. (Other shapes are rotated _by_ the keel to
+    // line up with the ellipse, so the ellipse itself is turned the opposite way.)
+    fn vertex(&self) -> Point {
+        Point::new(self.center.x + self.a, self.center.y).rotate(&self.center, -self.keel)
+    }
+
+    fn contains(&self, point: &Point) -> bool {
+        let p = point.rotate(&self.center, self.keel);
+        let x = p.x - self.center.x;
+        let y = p.y - self.center.y;
+
+        x.powf(2.) / self.a_squared + y.powf(2.) / self.b_squared <= 1.
+    }
+
+    // the top-left and bottom-right corners of the smallest (unrotated) box around the ellipse
+    pub fn bounds(&self) -> (Point, Point) {
+        let s = self.keel.sin().powf(2.);
+        let c = self.keel.cos().powf(2.);
+        let half_width = (self.a_squared * c + self.b_squared * s).sqrt();
+        let half_height = (self.a_squared * s + self.b_squared * c).sqrt();
+
+        (
+            Point::new(self.center.x - half_width, self.center.y - half_height),
+            Point::new(self.center.x + half_width, self.center.y + half_height),
+        )
+    }
+}
+
+// a closed shape made of straight edges, with the box around it precomputed
+#[derive(Debug)]
+pub struct Polygon {
+    edges: Vec<Line>,
+    top_left: Point,
+    bottom_right: Point,
+}
+
+impl Polygon {
+    pub fn new(points: &[Point]) -> Self {
+        let edges = points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(start, end)| Line::new(start.clone(), end.clone()))
+            .collect();
+        let top_left = points
+            .iter()
+            .fold(Point::new(f64::INFINITY, f64::INFINITY), |corner, p| {
+                Point::new(corner.x.min(p.x), corner.y.min(p.y))
+            });
+        let bottom_right = points.iter().fold(
+            Point::new(f64::NEG_INFINITY, f64::NEG_INFINITY),
+            |corner, p| Point::new(corner.x.max(p.x), corner.y.max(p.y)),
+        );
+
+        Polygon {
+            edges,
+            top_left,
+            bottom_right,
+        }
+    }
+
+    pub fn bounds(&self) -> (Point, Point) {
+        (self.top_left.clone(), self.bottom_right.clone())
+    }
+
+    // casts a ray to the right of the point, and counts how many edges it crosses: an odd number
+    // means the point is inside
+    fn contains(&self, point: &Point) -> bool {
+        self.edges
+            .iter()
+            .filter(|edge| {
+                (edge.start.y > point.y) != (edge.end.y > point.y)
+                    && point.x < edge.start.x + (point.y - edge.start.y) / edge.dy * edge.dx
+            })
+            .count()
+            % 2
+            == 1
+    }
+}
+
+// a stretch of an ellipse's outline, running between two angles. The angles are measured around
+// the ellipse before its keel is applied, so (a cos angle, b sin angle) from its center.
+#[derive(Debug)]
+pub struct Arc {
+    ellipse: Ellipse,
+    start: f64,
+    sweep: f64,
+}
+
+impl Arc {
+    pub fn new(ellipse: Ellipse, start: f64, end: f64) -> Self {
+        // it goes round from `start` to `end`, so a range can cross 0. Going all the way round (or
+        // more) is a full ellipse.
+        let sweep = match end - start {
+            sweep if sweep >= 2. * PI => 2. * PI,
+            sweep => sweep.rem_euclid(2. * PI),
+        };
+        Arc {
+            ellipse,
+            start,
+            sweep,
+        }
+    }
+
+    pub fn bounds(&self) -> (Point, Point) {
+        self.ellipse.bounds()
+    }
+
+    fn point_at(&self, angle: f64) -> Point {
+        let center = &self.ellipse.center;
+        Point::new(
+            center.x + self.ellipse.a * angle.cos(),
+            center.y + self.ellipse.b * angle.sin(),
+        )
+        .rotate(center, -self.ellipse.keel)
+    }
+
+    fn start_point(&self) -> Point {
+        self.point_at(self.start)
+    }
+
+    fn end_point(&self) -> Point {
+        self.point_at(self.start + self.sweep)
+    }
+
+    // whether a point on the ellipse falls within the arc
+    fn spans(&self, point: &Point) -> bool {
+        let center = &self.ellipse.center;
+        let p = point.rotate(center, self.ellipse.keel);
+        let angle = ((p.y - center.y) / self.ellipse.b).atan2((p.x - center.x) / self.ellipse.a);
+
+        (angle - self.start).rem_euclid(2. * PI) <= self.sweep
+    }
+}
+
+// a cubic Bézier curve: it starts at the first point, heads off towards the second, comes in from
+// the direction of the third, and ends at the fourth
+#[derive(Debug)]
+pub struct Bezier {
+    points: [Point; 4],
+    top_left: Point,
+    bottom_right: Point,
+}
+
+impl Bezier {
+    pub fn cubic(start: Point, control_1: Point, control_2: Point, end: Point) -> Self {
+        let xs = [start.x, control_1.x, control_2.x, end.x];
+        let ys = [start.y, control_1.y, control_2.y, end.y];
+
+        // the curve never leaves the box around its points
+        let top_left = Point::new(
+            xs.iter().cloned().fold(f64::INFINITY, f64::min),
+            ys.iter().cloned().fold(f64::INFINITY, f64::min),
+        );
+        let bottom_right = Point::new(
+            xs.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+            ys.iter().cloned().fold(f64::NEG_INFINITY, f64::max),
+        );
+
+        Bezier {
+            points: [start, control_1, control_2, end],
+            top_left,
+            bottom_right,
+        }
+    }
+
+    // any quadratic curve is also a cubic one, with control points 2/3 of the way from each end
+    // to the quadratic's control point
+    pub fn quadratic(start: Point, control: Point, end: Point) -> Self {
+        let control_1 = Point::new(
+            start.x + 2. / 3. * (control.x - start.x),
+            start.y + 2. / 3. * (control.y - start.y),
+        );
+        let control_2 = Point::new(
+            end.x + 2. / 3. * (control.x - end.x),
+            end.y + 2. / 3. * (control.y - end.y),
+        );
+
+        Bezier::cubic(start, control_1, control_2, end)
+    }
+
+    pub fn bounds(&self) -> (Point, Point) {
+        (self.top_left.clone(), self.bottom_right.clone())
+    }
+
+    // the curve along one axis, as a polynomial in t: [a, b, c, d] for a t³ + b t² + c t + d
+    fn coefficients<F>(&self, axis: F) -> [f64; 4]
+    where
+        F: Fn(&Point) -> f64,
+    {
+        let [p0, p1, p2, p3] = [
+            axis(&self.points[0]),
+            axis(&self.points[1]),
+            axis(&self.points[2]),
+            axis(&self.points[3]),
+        ];
+
+        [
+            -p0 + 3. * p1 - 3. * p2 + p3,
+            3. * p0 - 6. * p1 + 3. * p2,
+            -3. * p0 + 3. * p1,
+            p0,
+        ]
+    }
+}
+
+fn evaluate(coefficients: &[f64; 4], t: f64) -> f64 {
+    let [a, b, c, d] = *coefficients;
+    ((a * t + b) * t + c) * t + d
+}
+
+// the real roots of a t³ + b t² + c t + d that fall between 0 and 1
+fn cubic_roots(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    let scale = a.abs().max(b.abs()).max(c.abs()).max(d.abs());
+    let negligible = |n: f64| n.abs() <= scale * 0.000000000001;
+
+    let roots = if negligible(a) && negligible(b) {
+        if negligible(c) {
+            vec![]
+        } else {
+            vec![-d / c]
+        }
+    } else if negligible(a) {
+        let discriminant = c.powf(2.) - 4. * b * d;
+        if discriminant < 0. {
+            vec![]
+        } else {
+            let sqrt_discriminant = discriminant.sqrt();
+            vec![
+                (-c + sqrt_discriminant) / (2. * b),
+                (-c - sqrt_discriminant) / (2. * b),
+            ]
+        }
+    } else {
+        // substitute t = s - b/3 to get s³ + p s + q, which has a closed-form solution
+        let (b, c, d) = (b / a, c / a, d / a);
+        let shift = b / 3.;
+        let p = c - b.powf(2.) / 3.;
+        let q = 2. * b.powf(3.) / 27. - b * c / 3. + d;
+        let discriminant = (q / 2.).powf(2.) + (p / 3.).powf(3.);
+
+        if discriminant > 0. {
+            let sqrt_discriminant = discriminant.sqrt();
+            vec![
+                (-q / 2. + sqrt_discriminant).cbrt() + (-q / 2. - sqrt_discriminant).cbrt() - shift,
+            ]
+        } else if p == 0. {
+            vec![-shift]
+        } else {
+            let m = 2. * (-p / 3.).sqrt();
+            let theta = (3. * q / (p * m)).clamp(-1., 1.).acos() / 3.;
+            (0..3)
+                .map(|k| m * (theta - 2. * PI * k as f64 / 3.).cos() - shift)
+                .collect()
+        }
+    };
+
+    roots
+        .into_iter()
+        .filter(|t| -0.000000001 <= *t && *t <= 1.000000001)
+        .map(|t| t.clamp(0., 1.))
+        .collect()
 }
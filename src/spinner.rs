@@ -0,0 +1,179 @@
+use std::io::{self, IsTerminal, Write};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError, Sender};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+use crate::{terminal, Control, Frame, Grid, GridConfig, Pacer, TerminalGuard};
+
+/// An animation that keeps itself going on another thread while you get on with something else,
+/// like a loading spinner for a slow command. It's drawn right where the cursor is, in a
+/// `cell_width` x `cell_height` patch of the terminal, and the rest of the screen is left alone.
+///
+/// When you're done, [`stop`](Spinner::stop) it to rub it out, or
+/// [`finish_with`](Spinner::finish_with) a message to take its place. (Dropping it stops it,
+/// too.)
+///
+/// ```
+/// use just_asc::{Draw, Spinner};
+/// use std::f64::consts::PI;
+///
+/// let spinner = Spinner::start(
+///     just_asc::GridConfig {
+///         cell_width: 12,
+///         cell_height: 6,
+///         ..just_asc::DEFAULT_CONFIG
+///     },
+///     |grid: &mut just_asc::Grid, frame: just_asc::Frame| {
+///         let turns = frame.elapsed.as_secs_f64();
+///         grid.with_transform(|mut transform| {
+///             transform.translate(50., 50.).rotate(2. * PI * turns);
+///             transform.arc(0., 0., 40., 0., PI);
+///         });
+///     },
+/// );
+///
+/// // the real work goes here...
+///
+/// spinner.finish_with("all done!");
+/// ```
+///
+/// Nothing else should print to the terminal while it's spinning, or the two will end up drawing
+/// over each other. If stdout isn't a terminal (say it's going to a file), there's nothing to
+/// animate, so the spinner doesn't draw at all; [`finish_with`](Spinner::finish_with) still prints
+/// its message, though.
+#[derive(Debug)]
+pub struct Spinner {
+    // what to leave behind once it's done (if anything)
+    finish: Option<Sender<Option<String>>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+impl Spinner {
+    /// Starts spinning. Takes the same arguments as [`draw`](crate::draw): a [`GridConfig`], and a
+    /// drawing closure that gets a fresh [`Grid`] and the [`Frame`] it's drawing every frame.
+    ///
+    /// If the closure says to [`Stop`](Control::Stop), the last frame stays put until you finish
+    /// with the spinner.
+    pub fn start<F, R>(config: GridConfig, draw_fn: F) -> Spinner
+    where
+        F: FnMut(&mut Grid, Frame) -> R + Send + 'static,
+        R: Into<Control>,
+    {
+        if !io::stdout().is_terminal() {
+            return Spinner {
+                finish: None,
+                thread: None,
+            };
+        }
+
+        let grid = Grid::new(GridConfig {
+            inline: true,
+            ..config
+        });
+        let (sender, receiver) = mpsc::channel();
+        let thread = thread::spawn(move || spin(grid, draw_fn, receiver));
+        Spinner {
+            finish: Some(sender),
+            thread: Some(thread),
+        }
+    }
+
+    /// Stops the animation, and rubs it out. The cursor ends up back where it was when the spinner
+    /// started.
+    pub fn stop(mut self) {
+        self.finish(None);
+    }
+
+    /// Stops the animation, and prints `message` (and a newline) in its place.
+    pub fn finish_with<S: Into<String>>(mut self, message: S) {
+        self.finish(Some(message.into()));
+    }
+
+    fn finish(&mut self, message: Option<String>) {
+        match (self.finish.take(), self.thread.take()) {
+            (Some(finish), Some(thread)) => {
+                // if the thread's gone, it panicked, and we'll hear about it when we join it
+                let _ = finish.send(message);
+                if let Err(panic) = thread.join() {
+                    if !thread::panicking() {
+                        std::panic::resume_unwind(panic);
+                    }
+                }
+            }
+            _ => {
+                if let Some(message) = message {
+                    println!("{}", message);
+                }
+            }
+        }
+    }
+}
+
+impl Drop for Spinner {
+    fn drop(&mut self) {
+        self.finish(None);
+    }
+}
+
+// the spinner's thread: draws frames until it's told to finish, then cleans up after itself
+fn spin<F, R>(mut grid: Grid, mut draw_fn: F, finish: Receiver<Option<String>>)
+where
+    F: FnMut(&mut Grid, Frame) -> R,
+    R: Into<Control>,
+{
+    let _watch = grid.fit_terminal.then(terminal::watch_resize);
+    let mut pacer = Pacer::new(grid.max_framerate);
+    let _guard = TerminalGuard::inline().expect("failed printing to stdout");
+
+    let message = loop {
+        let frame = pacer.frame();
+        grid.refit();
+        let control = draw_fn(&mut grid, frame).into();
+        grid.print();
+        grid.clear();
+
+        // once it's stopped, there's nothing left to do but wait to be told to finish
+        let mut finished = None;
+        if control == Control::Stop {
+            finished = wait(&finish, None);
+        } else {
+            // otherwise, same as `draw`: sleep until the next frame, unless we're told to finish
+            // first
+            let mut sleep = |duration| {
+                if finished.is_none() {
+                    finished = wait(&finish, Some(duration));
+                }
+            };
+            match control {
+                Control::Continue | Control::Stop => {}
+                Control::Pause(duration) => pacer.pause(duration, &mut sleep),
+                Control::SetFramerate(target_fps) => pacer.set_framerate(target_fps),
+            }
+            pacer.wait(&mut sleep);
+        }
+        if let Some(message) = finished {
+            break message;
+        }
+    };
+
+    let mut stdout = io::stdout().lock();
+    grid.erase_inline(&mut stdout)
+        .expect("failed printing to stdout");
+    if let Some(message) = message {
+        writeln!(stdout, "{}", message).expect("failed printing to stdout");
+    }
+    stdout.flush().expect("failed printing to stdout");
+}
+
+// waits for a message to finish with, for up to `timeout` (or as long as it takes). `None` if
+// there wasn't one yet.
+fn wait(finish: &Receiver<Option<String>>, timeout: Option<Duration>) -> Option<Option<String>> {
+    match timeout {
+        Some(timeout) => match finish.recv_timeout(timeout) {
+            Ok(message) => Some(message),
+            Err(RecvTimeoutError::Timeout) => None,
+            Err(RecvTimeoutError::Disconnected) => Some(None),
+        },
+        None => Some(finish.recv().unwrap_or(None)),
+    }
+}
@@ -0,0 +1,522 @@
+//! Getting the terminal ready for an animation, putting it back the way we found it, finding
+//! out how big it is, and reading the keyboard.
+//!
+//! [`draw`](crate::draw) takes care of this for you; you'd only reach for a [`TerminalGuard`] (or
+//! a [`Keyboard`]) yourself if you're sending frames to the terminal some other way (say, with
+//! [`Grid::render_changes_to`](crate::Grid::render_changes_to)).
+
+#[cfg(unix)]
+use std::cell::UnsafeCell;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, AtomicU8, AtomicUsize, Ordering};
+use std::sync::Once;
+#[cfg(unix)]
+use std::sync::{Mutex, OnceLock, PoisonError};
+
+use crate::Key;
+
+// switch to the alternate screen, clear it, home the cursor and hide it
+const ENTER: &str = "\x1b[?1049h\x1b[2J\x1b[1;1H\x1b[?25l";
+// show the cursor, and switch back to the normal screen (and its scrollback)
+const LEAVE: &str = "\x1b[?25h\x1b[?1049l";
+// inline, we stay on the normal screen and only hide the cursor
+const ENTER_INLINE: &str = "\x1b[?25l";
+const LEAVE_INLINE: &str = "\x1b[?25h";
+
+// how the terminal currently needs putting back, if at all. Restoring is a swap on this, so
+// however many ways we find out we're done (drop, panic, Ctrl-C), it only happens once.
+const IDLE: u8 = 0;
+const FULL_SCREEN: u8 = 1;
+const INLINE: u8 = 2;
+static ACTIVE: AtomicU8 = AtomicU8::new(IDLE);
+static INSTALL_PANIC_HOOK: Once = Once::new();
+
+// Ctrl-C, for as long as there's a guard or keyboard around to put back
+#[cfg(unix)]
+static INTERRUPT: Hook = Hook::new(libc::SIGINT, on_interrupt);
+
+// Ctrl-Z, likewise. The terminal's put back while we're stopped, and taken over again when we
+// carry on, which is how many times this has gone up, so grids know to draw everything again.
+#[cfg(unix)]
+static SUSPEND: Hook = Hook::new(libc::SIGTSTP, on_suspend);
+static RESUMES: AtomicUsize = AtomicUsize::new(0);
+
+// how many times the terminal's changed size. Nobody resets it, so any number of grids can each
+// keep track of which size they last fit.
+static RESIZES: AtomicUsize = AtomicUsize::new(0);
+#[cfg(unix)]
+static RESIZE: Hook = Hook::new(libc::SIGWINCH, on_resize);
+
+// stdin's settings from before we first switched it to raw mode, and whether they need putting
+// back. (Like `ACTIVE`, this gets swapped, so it only happens once.)
+#[cfg(unix)]
+static COOKED: OnceLock<libc::termios> = OnceLock::new();
+static RAW: AtomicBool = AtomicBool::new(false);
+
+/// Takes over the terminal for as long as it's alive: switches to the alternate screen (so your
+/// scrollback is left alone) and hides the cursor. When it's dropped, everything goes back to
+/// normal.
+///
+/// It also puts things back if your program panics, or if someone hits Ctrl-C. (Things it _can't_
+/// clean up after: `std::process::exit`, or a signal nobody can catch, like `SIGKILL`.) If your
+/// program handles Ctrl-C itself, its handler still gets called, once the terminal's been put
+/// back, and it's left in charge again when the last guard goes.
+///
+/// Ctrl-Z puts things back too, while your program's stopped, and takes the terminal over again
+/// when it carries on (`fg`). Whatever's drawing will need to start again from scratch, which
+/// [`draw`](crate::draw) does for you.
+///
+/// ```
+/// let guard = just_asc::TerminalGuard::new().unwrap();
+/// // draw to your heart's content...
+/// drop(guard); // ...and we're back where we started
+/// ```
+///
+/// If you'd rather draw in amongst the rest of your program's output, there's
+/// [`TerminalGuard::inline`], which only hides the cursor.
+#[derive(Debug)]
+pub struct TerminalGuard {
+    // no making these without `new`
+    _private: (),
+}
+
+impl TerminalGuard {
+    /// Switches to the alternate screen and hides the cursor. Fails if we can't write to stdout.
+    pub fn new() -> io::Result<TerminalGuard> {
+        TerminalGuard::enter(FULL_SCREEN)
+    }
+
+    /// Leaves the screen (and whatever's on it) alone, and just hides the cursor. It's shown again
+    /// when the guard's dropped, or on a panic or Ctrl-C, same as with [`TerminalGuard::new`].
+    pub fn inline() -> io::Result<TerminalGuard> {
+        TerminalGuard::enter(INLINE)
+    }
+
+    fn enter(mode: u8) -> io::Result<TerminalGuard> {
+        INSTALL_PANIC_HOOK.call_once(install_panic_hook);
+        hook_signals();
+        // from here on, dropping the guard (even if we bail out) unhooks Ctrl-C again
+        let guard = TerminalGuard { _private: () };
+
+        let (enter, _) = codes(mode).expect("not a mode the terminal can be in");
+        let mut stdout = io::stdout().lock();
+        stdout.write_all(enter.as_bytes())?;
+        stdout.flush()?;
+        ACTIVE.store(mode, Ordering::SeqCst);
+
+        Ok(guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        restore();
+        unhook_signals();
+    }
+}
+
+// the escape codes that take the terminal over in `mode`, and that put it back afterwards
+fn codes(mode: u8) -> Option<(&'static str, &'static str)> {
+    match mode {
+        FULL_SCREEN => Some((ENTER, LEAVE)),
+        INLINE => Some((ENTER_INLINE, LEAVE_INLINE)),
+        _ => None,
+    }
+}
+
+// the escape codes that put the terminal back, if it still needs it (and marks it as done)
+fn leave() -> Option<&'static str> {
+    codes(ACTIVE.swap(IDLE, Ordering::SeqCst)).map(|(_, leave)| leave)
+}
+
+// how many times we've carried on after Ctrl-Z: if it's gone up since the last time you asked,
+// the terminal needs drawing again from scratch
+pub(crate) fn resumes() -> usize {
+    RESUMES.load(Ordering::SeqCst)
+}
+
+// the signals a guard or keyboard needs handling while it's around
+fn hook_signals() {
+    #[cfg(unix)]
+    {
+        INTERRUPT.hook();
+        SUSPEND.hook();
+    }
+}
+
+fn unhook_signals() {
+    #[cfg(unix)]
+    {
+        SUSPEND.unhook();
+        INTERRUPT.unhook();
+    }
+}
+
+// puts the terminal back, if it still needs it
+fn restore() {
+    if let Some(codes) = leave() {
+        let mut stdout = io::stdout().lock();
+        // there's nobody to tell if these fail, and no point panicking on the way out
+        let _ = stdout.write_all(codes.as_bytes());
+        let _ = stdout.flush();
+    }
+}
+
+fn install_panic_hook() {
+    // restore before the panic message prints, so it ends up on the normal screen where you can
+    // actually read it
+    let previous = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        restore();
+        restore_keyboard();
+        previous(info);
+    }));
+}
+
+// Ctrl-C. Signal handlers can't do much safely (no locks, so no `io::stdout`), so this writes the
+// escape codes straight to the file descriptor. Then it's whoever was handling Ctrl-C before us:
+// if that was nobody, the default handler kills us like it would have anyway.
+#[cfg(unix)]
+extern "C" fn on_interrupt(
+    signal: libc::c_int,
+    info: *mut libc::siginfo_t,
+    context: *mut libc::c_void,
+) {
+    restore_keyboard();
+    if let Some(codes) = leave() {
+        write_raw(codes);
+    }
+    unsafe {
+        let previous = INTERRUPT.previous();
+        if previous.sa_sigaction == libc::SIG_DFL {
+            libc::sigaction(signal, previous, std::ptr::null_mut());
+            libc::raise(signal);
+        } else {
+            INTERRUPT.forward(signal, info, context);
+        }
+    }
+}
+
+// Ctrl-Z. Same as Ctrl-C as far as putting the terminal back goes, but if the default handler
+// stops us, `raise` only comes back once we've been carried on (`fg`), and then we take the
+// terminal over again, the way it was.
+#[cfg(unix)]
+extern "C" fn on_suspend(
+    signal: libc::c_int,
+    info: *mut libc::siginfo_t,
+    context: *mut libc::c_void,
+) {
+    let previous = SUSPEND.previous();
+    if previous.sa_sigaction == libc::SIG_IGN {
+        return;
+    }
+
+    let raw = RAW.load(Ordering::SeqCst);
+    restore_keyboard();
+    let mode = ACTIVE.swap(IDLE, Ordering::SeqCst);
+    if let Some((_, leave)) = codes(mode) {
+        write_raw(leave);
+    }
+
+    unsafe {
+        if previous.sa_sigaction == libc::SIG_DFL {
+            // the signal's blocked while we're handling it, so it'd only stop us once we'd
+            // returned (and put everything back): unblock it, and stop right here
+            libc::sigaction(signal, previous, std::ptr::null_mut());
+            let mut blocked: libc::sigset_t = std::mem::zeroed();
+            libc::sigemptyset(&mut blocked);
+            libc::sigaddset(&mut blocked, signal);
+            libc::pthread_sigmask(libc::SIG_UNBLOCK, &blocked, std::ptr::null_mut());
+            libc::raise(signal);
+            libc::sigaction(signal, &SUSPEND.action(), std::ptr::null_mut());
+        } else {
+            SUSPEND.forward(signal, info, context);
+        }
+    }
+
+    // carrying on. (Unless the guard went while we were stopped, in which case there's nothing
+    // to take over.)
+    if let Some((enter, _)) = codes(mode) {
+        if ACTIVE
+            .compare_exchange(IDLE, mode, Ordering::SeqCst, Ordering::SeqCst)
+            .is_ok()
+        {
+            write_raw(enter);
+        }
+    }
+    if raw {
+        if let Some(cooked) = COOKED.get() {
+            unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw_mode(*cooked)) };
+            RAW.store(true, Ordering::SeqCst);
+        }
+    }
+    RESUMES.fetch_add(1, Ordering::SeqCst);
+}
+
+// writes straight to stdout's file descriptor: signal handlers can't lock `io::stdout`
+#[cfg(unix)]
+fn write_raw(codes: &str) {
+    unsafe {
+        libc::write(
+            libc::STDOUT_FILENO,
+            codes.as_ptr() as *const libc::c_void,
+            codes.len(),
+        );
+    }
+}
+
+// a signal we handle ourselves for as long as something needs us to, and whatever was handling it
+// before. We pass the signal along to that, and put it back once nothing needs ours any more.
+#[cfg(unix)]
+struct Hook {
+    signal: libc::c_int,
+    handler: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void),
+    // how many things need our handler right now
+    users: Mutex<usize>,
+    // only written while our handler isn't installed, so reading it from the handler is fine
+    previous: UnsafeCell<libc::sigaction>,
+}
+
+#[cfg(unix)]
+unsafe impl Sync for Hook {}
+
+#[cfg(unix)]
+impl Hook {
+    const fn new(
+        signal: libc::c_int,
+        handler: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void),
+    ) -> Hook {
+        Hook {
+            signal,
+            handler,
+            users: Mutex::new(0),
+            previous: UnsafeCell::new(unsafe { std::mem::zeroed() }),
+        }
+    }
+
+    // installs our handler, if it isn't already, remembering the one it replaces
+    fn hook(&self) {
+        let mut users = self.users.lock().unwrap_or_else(PoisonError::into_inner);
+        if *users == 0 {
+            unsafe {
+                libc::sigaction(self.signal, std::ptr::null(), self.previous.get());
+                libc::sigaction(self.signal, &self.action(), std::ptr::null_mut());
+            }
+        }
+        *users += 1;
+    }
+
+    // puts the old handler back, if nothing else needs ours
+    fn unhook(&self) {
+        let mut users = self.users.lock().unwrap_or_else(PoisonError::into_inner);
+        *users = users.saturating_sub(1);
+        if *users == 0 {
+            unsafe {
+                libc::sigaction(self.signal, self.previous.get(), std::ptr::null_mut());
+            }
+        }
+    }
+
+    // our handler, as `sigaction` wants it
+    fn action(&self) -> libc::sigaction {
+        unsafe {
+            let mut action: libc::sigaction = std::mem::zeroed();
+            action.sa_sigaction = self.handler as libc::sighandler_t;
+            action.sa_flags = libc::SA_SIGINFO | libc::SA_RESTART;
+            libc::sigemptyset(&mut action.sa_mask);
+            action
+        }
+    }
+
+    // the handler that was there before ours
+    fn previous(&self) -> &libc::sigaction {
+        unsafe { &*self.previous.get() }
+    }
+
+    // passes a signal along to the handler that was there before ours. (If that was the default
+    // one, or the signal was being ignored, there's nothing to call.)
+    unsafe fn forward(
+        &self,
+        signal: libc::c_int,
+        info: *mut libc::siginfo_t,
+        context: *mut libc::c_void,
+    ) {
+        let previous = self.previous();
+        match previous.sa_sigaction {
+            libc::SIG_DFL | libc::SIG_IGN => {}
+            handler if previous.sa_flags & libc::SA_SIGINFO != 0 => {
+                let handler: extern "C" fn(libc::c_int, *mut libc::siginfo_t, *mut libc::c_void) =
+                    std::mem::transmute(handler);
+                handler(signal, info, context);
+            }
+            handler => {
+                let handler: extern "C" fn(libc::c_int) = std::mem::transmute(handler);
+                handler(signal);
+            }
+        }
+    }
+}
+
+/// The size of the terminal that stdout is going to, as `(columns, rows)`. `None` if stdout isn't
+/// a terminal, or we can't tell how big it is (which, for now, includes everywhere but Unix).
+pub fn size() -> Option<(usize, usize)> {
+    #[cfg(unix)]
+    unsafe {
+        let mut size: libc::winsize = std::mem::zeroed();
+        if libc::ioctl(libc::STDOUT_FILENO, libc::TIOCGWINSZ, &mut size) == 0
+            && size.ws_col > 0
+            && size.ws_row > 0
+        {
+            return Some((size.ws_col as usize, size.ws_row as usize));
+        }
+    }
+    None
+}
+
+// keeps an eye out for the terminal changing size (see `resizes`), for as long as it's alive
+#[derive(Debug)]
+pub(crate) struct ResizeWatch {
+    // no making these without `watch_resize`
+    _private: (),
+}
+
+pub(crate) fn watch_resize() -> ResizeWatch {
+    #[cfg(unix)]
+    RESIZE.hook();
+    ResizeWatch { _private: () }
+}
+
+impl Drop for ResizeWatch {
+    fn drop(&mut self) {
+        #[cfg(unix)]
+        RESIZE.unhook();
+    }
+}
+
+// how many times the terminal's changed size (since we started watching): if it's gone up since
+// the last time you asked, it's been resized
+pub(crate) fn resizes() -> usize {
+    RESIZES.load(Ordering::SeqCst)
+}
+
+#[cfg(unix)]
+extern "C" fn on_resize(
+    signal: libc::c_int,
+    info: *mut libc::siginfo_t,
+    context: *mut libc::c_void,
+) {
+    RESIZES.fetch_add(1, Ordering::SeqCst);
+    unsafe { RESIZE.forward(signal, info, context) };
+}
+
+/// Reads keys straight off the keyboard, for as long as it's alive. Normally the terminal holds on
+/// to what you type until you hit Enter (and echoes it on the screen as you go); this switches
+/// stdin to "raw" mode, so every key comes through as soon as it's pressed, and nothing gets
+/// echoed. When it's dropped, stdin goes back to normal.
+///
+/// Ctrl-C still stops your program, and like [`TerminalGuard`], stdin gets put back on a panic or
+/// Ctrl-C too. Ctrl-Z puts it back while your program's stopped, and switches it to raw mode again
+/// when it carries on.
+///
+/// ```no_run
+/// let mut keyboard = just_asc::terminal::Keyboard::new().unwrap();
+/// loop {
+///     for key in keyboard.keys() {
+///         println!("{:?}", key);
+///     }
+///     std::thread::sleep(std::time::Duration::from_millis(50));
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Keyboard {
+    // no making these without `new`
+    _private: (),
+}
+
+impl Keyboard {
+    /// Switches stdin to raw mode. Fails if stdin isn't a terminal (which, for now, includes
+    /// everywhere but Unix).
+    pub fn new() -> io::Result<Keyboard> {
+        INSTALL_PANIC_HOOK.call_once(install_panic_hook);
+        hook_signals();
+        // from here on, dropping the keyboard (even if we bail out) unhooks Ctrl-C again
+        let keyboard = Keyboard { _private: () };
+
+        #[cfg(unix)]
+        unsafe {
+            let mut termios: libc::termios = std::mem::zeroed();
+            if libc::tcgetattr(libc::STDIN_FILENO, &mut termios) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let raw = raw_mode(*COOKED.get_or_init(|| termios));
+            if libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &raw) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            RAW.store(true, Ordering::SeqCst);
+            Ok(keyboard)
+        }
+
+        #[cfg(not(unix))]
+        {
+            drop(keyboard);
+            Err(io::Error::new(
+                io::ErrorKind::Unsupported,
+                "can't read the keyboard here yet",
+            ))
+        }
+    }
+
+    /// The keys that have been pressed since the last time you asked, oldest first. If there
+    /// aren't any, you get an empty `Vec` right away: it never waits for a key.
+    pub fn keys(&mut self) -> Vec<Key> {
+        let mut bytes = Vec::new();
+        #[cfg(unix)]
+        loop {
+            let mut buffer = [0; 64];
+            let read = unsafe {
+                libc::read(
+                    libc::STDIN_FILENO,
+                    buffer.as_mut_ptr() as *mut libc::c_void,
+                    buffer.len(),
+                )
+            };
+            if read <= 0 {
+                break;
+            }
+            bytes.extend_from_slice(&buffer[..read as usize]);
+        }
+        Key::parse(&bytes)
+    }
+}
+
+impl Drop for Keyboard {
+    fn drop(&mut self) {
+        restore_keyboard();
+        unhook_signals();
+    }
+}
+
+// stdin's settings with raw mode on: no waiting for Enter, no echoing, and no special treatment
+// for Ctrl-S, Ctrl-Q or Ctrl-V (but Ctrl-C and Ctrl-Z still work). Reads come straight back with
+// whatever's been typed, even if that's nothing.
+#[cfg(unix)]
+fn raw_mode(mut termios: libc::termios) -> libc::termios {
+    termios.c_lflag &= !(libc::ICANON | libc::ECHO | libc::IEXTEN);
+    termios.c_iflag &= !libc::IXON;
+    termios.c_cc[libc::VMIN] = 0;
+    termios.c_cc[libc::VTIME] = 0;
+    termios
+}
+
+// puts stdin back how it was, if it still needs it. This gets called from the Ctrl-C handler, so
+// no locks (`tcsetattr` is fine)
+fn restore_keyboard() {
+    if RAW.swap(false, Ordering::SeqCst) {
+        #[cfg(unix)]
+        unsafe {
+            if let Some(cooked) = COOKED.get() {
+                libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, cooked);
+            }
+        }
+    }
+}
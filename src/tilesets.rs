@@ -1,5 +1,166 @@
 //! Out-of-the-box tilesets for your [GridConfig](crate::GridConfig).
 
+/// The characters a [Grid](crate::Grid) draws with, along with how finely each cell is split up.
+///
+/// Each variant holds one character for every combination of filled-in regions of a cell (see
+/// [GridConfig::tileset](crate::GridConfig::tileset) for how they're ordered).
+// tilesets get copied into a grid once, so their size isn't much of a worry
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, Copy)]
+pub enum Tileset {
+    /// Cells are split into 2x2 quadrants, so there are 16 characters.
+    Quadrants([char; 16]),
+
+    /// Cells are split into 2 columns and 3 rows, so there are 64 characters.
+    Sextants([char; 64]),
+
+    /// Cells are split into 2 columns and 4 rows, so there are 256 characters.
+    Octants([char; 256]),
+}
+
+impl Tileset {
+    // how many columns and rows of regions each cell is split into
+    pub(crate) fn layout(&self) -> (usize, usize) {
+        match self {
+            Tileset::Quadrants(_) => (2, 2),
+            Tileset::Sextants(_) => (2, 3),
+            Tileset::Octants(_) => (2, 4),
+        }
+    }
+
+    fn glyphs(&self) -> &[char] {
+        match self {
+            Tileset::Quadrants(glyphs) => glyphs,
+            Tileset::Sextants(glyphs) => glyphs,
+            Tileset::Octants(glyphs) => glyphs,
+        }
+    }
+
+    pub(crate) fn glyph(&self, filled: u8) -> char {
+        self.glyphs()[filled as usize]
+    }
+
+    /// Checks that every character in the tileset fills in the same parts of its cell as the
+    /// regions it stands for, and returns the indexes of any that don't. Handy for testing your
+    /// own tilesets!
+    ///
+    /// Only characters with a well-known shape can be checked: spaces, block elements (like `▚`),
+    /// sextants and Braille. Anything else (like the letters in [`PURE_ASCII`]) is up to your
+    /// judgment, and gets skipped.
+    ///
+    /// ```
+    /// use just_asc::tilesets;
+    ///
+    /// for tileset in [
+    ///     tilesets::PURE_ASCII,
+    ///     tilesets::BRAILLE,
+    ///     tilesets::QUADRANT_BLOCKS,
+    ///     tilesets::SEXTANTS,
+    ///     tilesets::DENSE_BRAILLE,
+    /// ]
+    /// .iter()
+    /// {
+    ///     assert_eq!(tileset.mismatches(), Vec::<usize>::new());
+    /// }
+    ///
+    /// let oops = tilesets::Tileset::Quadrants([
+    ///     ' ', '▗', '▖', '▄', '▝', '▐', '▞', '▟', '▘', '▚', '▌', '▙', '▀', '▜', '▙', '█',
+    /// ]);
+    /// assert_eq!(oops.mismatches(), vec![0b1110]); // should be '▛'!
+    /// ```
+    pub fn mismatches(&self) -> Vec<usize> {
+        let (columns, rows) = self.layout();
+        self.glyphs()
+            .iter()
+            .enumerate()
+            .filter(|&(filled, &glyph)| match glyph_shape(glyph) {
+                Some(shape) => shape != regions_shape(filled, columns, rows),
+                None => false,
+            })
+            .map(|(filled, _)| filled)
+            .collect()
+    }
+}
+
+// To compare glyphs and regions across layouts, both get drawn onto a 2 column, 12 row "shape"
+// (12 rows divide evenly into 2, 3 or 4 rows of regions). Each bit is one spot on the shape,
+// left-to-right, top-to-bottom, starting from the lowest bit.
+const SHAPE_ROWS: usize = 12;
+
+// fills in the spots covered by one region (or glyph part) of a cell split into `rows` rows
+fn region_spots(column: usize, row: usize, rows: usize) -> u32 {
+    let height = SHAPE_ROWS / rows;
+    (row * height..(row + 1) * height).fold(0, |spots, r| spots | 1 << (r * 2 + column))
+}
+
+fn regions_shape(filled: usize, columns: usize, rows: usize) -> u32 {
+    let count = columns * rows;
+    (0..count)
+        .filter(|k| filled & (1 << (count - 1 - k)) != 0)
+        .fold(0, |spots, k| {
+            spots | region_spots(k % columns, k / columns, rows)
+        })
+}
+
+// the shape of a character, if it's one we know
+fn glyph_shape(glyph: char) -> Option<u32> {
+    let quadrants = |[top_left, top_right, bottom_left, bottom_right]: [bool; 4]| {
+        [top_left, top_right, bottom_left, bottom_right]
+            .iter()
+            .enumerate()
+            .filter(|(_, &filled)| filled)
+            .fold(0, |spots, (k, _)| spots | region_spots(k % 2, k / 2, 2))
+    };
+
+    let code = glyph as u32;
+    let shape = match glyph {
+        ' ' | '\u{2800}' => 0,
+        '█' => quadrants([true, true, true, true]),
+        '▀' => quadrants([true, true, false, false]),
+        '▄' => quadrants([false, false, true, true]),
+        '▌' => quadrants([true, false, true, false]),
+        '▐' => quadrants([false, true, false, true]),
+        '▘' => quadrants([true, false, false, false]),
+        '▝' => quadrants([false, true, false, false]),
+        '▖' => quadrants([false, false, true, false]),
+        '▗' => quadrants([false, false, false, true]),
+        '▚' => quadrants([true, false, false, true]),
+        '▞' => quadrants([false, true, true, false]),
+        '▛' => quadrants([true, true, true, false]),
+        '▜' => quadrants([true, true, false, true]),
+        '▙' => quadrants([true, false, true, true]),
+        '▟' => quadrants([false, true, true, true]),
+        '\u{2801}'..='\u{28ff}' => {
+            (0..8)
+                .filter(|dot| (code - 0x2800) & (1 << dot) != 0)
+                .fold(0, |spots, dot| {
+                    let (column, row) = match dot {
+                        6 => (0, 3),
+                        7 => (1, 3),
+                        dot => (dot / 3, dot % 3),
+                    };
+                    spots | region_spots(column as usize, row as usize, 4)
+                })
+        }
+        '\u{1fb00}'..='\u{1fb3b}' => {
+            // undo the skipped combinations (see `sextants`)
+            let mut sextants = code - 0x1fb00 + 1;
+            if sextants >= 21 {
+                sextants += 1;
+            }
+            if sextants >= 42 {
+                sextants += 1;
+            }
+            (0..6)
+                .filter(|k| sextants & (1 << k) != 0)
+                .fold(0, |spots, k| spots | region_spots(k % 2, k / 2, 3))
+        }
+        _ => return None,
+    };
+
+    Some(shape)
+}
+
 /// Actual ASCII characters. Nice and crunchy.
 ///
 /// ```text
@@ -25,7 +186,7 @@
 ///            ""¶____________P""
 /// ```
 ///
-pub const PURE_ASCII: [char; 16] = [
+pub const PURE_ASCII: Tileset = Tileset::Quadrants([
     ' ',  // 0000
     '.',  // 0001
     ',',  // 0010
@@ -39,10 +200,10 @@ pub const PURE_ASCII: [char; 16] = [
     '[',  // 1010
     'b',  // 1011
     '"',  // 1100
-    '¶', //  1101
+    '¶',  //  1101
     'P',  // 1110
     '#',  // 1111
-];
+]);
 
 /// Uses Braille characters to get clean, true-to-form shapes. It's not ASCII, but it looks great!
 ///
@@ -69,7 +230,7 @@ pub const PURE_ASCII: [char; 16] = [
 /// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠛⠛⢻⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⣤⡟⠛⠛⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
 /// ```
 ///
-pub const BRAILLE: [char; 16] = [
+pub const BRAILLE: Tileset = Tileset::Quadrants([
     '\u{2800}', // 0000
     '\u{28a0}', // 0001
     '\u{2844}', // 0010
@@ -86,4 +247,190 @@ pub const BRAILLE: [char; 16] = [
     '\u{28bb}', // 1101
     '\u{285f}', // 1110
     '\u{28ff}', // 1111
-];
+]);
+
+/// Unicode's quadrant block elements, which match up exactly with each cell's quadrants. Solid and
+/// blocky, like a really old video game.
+///
+/// ```text
+///           ▗▄▄▛▀▀▀▀▀▀▀▀▀▀▜▄▄▖
+///        ▗▟▀▀                ▀▀▙▖
+///      ▄▛▀                      ▀▜▄
+///    ▗▟▘                          ▝▙▖
+///   ▗▛                              ▜▖
+///  ▗▛                                ▜▖
+/// ▗▛                                  ▜▖
+/// ▟           ▀▙▖        ▗▟▀           ▙
+/// ▌             ▀▙▖    ▗▟▀             ▐
+/// ▌               ▀▙▖▗▟▀               ▐
+/// ▌                 ██                 ▐
+/// ▌               ▄▛▘▝▜▄               ▐
+/// ▜             ▄▛▘    ▝▜▄             ▛
+/// ▝▙          ▄▛▘        ▝▜▄          ▟▘
+///  ▝▙                                ▟▘
+///   ▝▙                              ▟▘
+///    ▝▜▖                          ▗▛▘
+///      ▀▙▄                      ▄▟▀
+///        ▝▜▄▄                ▄▄▛▘
+///           ▝▀▀▙▄▄▄▄▄▄▄▄▄▄▟▀▀▘
+/// ```
+///
+pub const QUADRANT_BLOCKS: Tileset = Tileset::Quadrants([
+    ' ', // 0000
+    '▗', // 0001
+    '▖', // 0010
+    '▄', // 0011
+    '▝', // 0100
+    '▐', // 0101
+    '▞', // 0110
+    '▟', // 0111
+    '▘', // 1000
+    '▚', // 1001
+    '▌', // 1010
+    '▙', // 1011
+    '▀', // 1100
+    '▜', // 1101
+    '▛', // 1110
+    '█', // 1111
+]);
+
+/// The "sextant" block characters added in Unicode 13, which split each cell into 2x3 blocks.
+/// Solid shapes with a bit more detail than [`BRAILLE`]; you'll need a fairly recent font.
+///
+/// ```text
+///           🬞🬭🬚🬋🬆🬂🬂🬂🬂🬂🬂🬂🬂🬊🬋🬩🬭🬏
+///        🬞🬚🬍🬂                🬂🬌🬩🬏
+///      🬭🬜🬂                      🬂🬪🬭
+///    🬞🬜🬀                          🬁🬪🬏
+///   🬦🬆                              🬊🬓
+///  🬦🬆                                🬊🬓
+/// 🬞🬕                                  🬨🬏
+/// 🬷           🬊🬱🬏        🬞🬵🬆           🬲
+/// ▌             🬊🬱🬭🬹🬹🬹🬹🬭🬵🬆             ▐
+/// ▌              🬞██████🬏              ▐
+/// ▌              🬁██████🬀              ▐
+/// ▌               🬷🬎🬎🬎🬎🬲               ▐
+/// 🬨             🬵🬆🬀    🬁🬊🬱             🬕
+/// 🬁🬲          🬵🬆🬀        🬁🬊🬱          🬷🬀
+///  🬉🬱                                🬵🬄
+///   🬉🬱                              🬵🬄
+///    🬁🬪🬏                          🬞🬜🬀
+///      🬂🬪🬭                      🬭🬜🬂
+///        🬁🬌🬩🬭                🬭🬚🬍🬀
+///           🬁🬂🬌🬋🬱🬭🬭🬭🬭🬭🬭🬭🬭🬵🬋🬍🬂🬀
+/// ```
+///
+/// ```
+/// use just_asc::Draw;
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 2,
+///     cell_height: 1,
+///     tileset: just_asc::tilesets::SEXTANTS,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.fill_rect(0., 0., 100., 30.); // just the top third of each cell
+///
+/// assert_eq!(grid.to_string(), "\u{1fb02}\u{1fb02}\n");
+/// ```
+pub const SEXTANTS: Tileset = Tileset::Sextants(sextants());
+
+// Unicode numbers sextants 1-6 left-to-right, top-to-bottom, with sextant 1 as the lowest bit
+// (the opposite of our order). The block starting at U+1FB00 covers every combination except the
+// four that already had characters: empty, full, and the left and right halves.
+const fn sextants() -> [char; 64] {
+    let mut glyphs = [' '; 64];
+    let mut filled = 0;
+    while filled < 64 {
+        let mut sextants = 0;
+        let mut region = 0;
+        while region < 6 {
+            if filled & (1 << (5 - region)) != 0 {
+                sextants |= 1 << region;
+            }
+            region += 1;
+        }
+        let code = match sextants {
+            0 => 0x20,
+            21 => 0x258c,
+            42 => 0x2590,
+            63 => 0x2588,
+            n if n < 21 => 0x1fb00 + n - 1,
+            n if n < 42 => 0x1fb00 + n - 2,
+            n => 0x1fb00 + n - 3,
+        };
+        glyphs[filled] = match char::from_u32(code) {
+            Some(glyph) => glyph,
+            None => ' ',
+        };
+        filled += 1;
+    }
+    glyphs
+}
+
+/// Braille characters again, but this time using all eight of their dots: each cell is split into
+/// 2x4 regions rather than 2x2 quadrants, so you get twice the vertical detail of [`BRAILLE`].
+///
+/// ```text
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⣠⠤⠖⠚⠉⠉⠉⠉⠉⠉⠉⠉⠓⠲⠤⣄⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⢀⡴⠚⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠓⢦⡀⠀⠀⠀⠀⠀⠀⠀
+/// ⠀⠀⠀⠀⠀⣠⠞⠉⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠉⠳⣄⠀⠀⠀⠀⠀
+/// ⠀⠀⠀⢀⡼⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⢧⡀⠀⠀⠀
+/// ⠀⠀⢠⠏⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠹⡄⠀⠀
+/// ⠀⢠⠏⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠹⡄⠀
+/// ⢀⡏⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢹⡀
+/// ⡼⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⢦⡀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡴⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢧
+/// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⢦⡀⠀⠀⠀⠀⢀⡴⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+/// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠙⢦⡀⢀⡴⠋⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+/// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣹⣏⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+/// ⡇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠⠞⠁⠈⠳⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢸
+/// ⢳⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠⠞⠁⠀⠀⠀⠀⠈⠳⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⡞
+/// ⠈⣇⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣠⠞⠁⠀⠀⠀⠀⠀⠀⠀⠀⠈⠳⣄⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣸⠁
+/// ⠀⠘⣆⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣰⠃⠀
+/// ⠀⠀⠘⣆⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣰⠃⠀⠀
+/// ⠀⠀⠀⠈⢳⡀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⢀⡞⠁⠀⠀⠀
+/// ⠀⠀⠀⠀⠀⠙⢦⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⡴⠋⠀⠀⠀⠀⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠈⠳⢤⣀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⣀⡤⠞⠁⠀⠀⠀⠀⠀⠀⠀
+/// ⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀⠈⠙⠒⠦⢤⣀⣀⣀⣀⣀⣀⣀⣀⡤⠴⠒⠋⠁⠀⠀⠀⠀⠀⠀⠀⠀⠀⠀
+/// ```
+///
+/// Each dot is its own region, so a line across the top of a cell only fills the top row of dots:
+///
+/// ```
+/// use just_asc::Draw;
+///
+/// let mut grid = just_asc::Grid::new(just_asc::GridConfig {
+///     cell_width: 1,
+///     cell_height: 1,
+///     tileset: just_asc::tilesets::DENSE_BRAILLE,
+///     ..just_asc::DEFAULT_CONFIG
+/// });
+/// grid.line(0., 10., 100., 10.);
+///
+/// assert_eq!(grid.to_string(), "\u{2809}\n");
+/// ```
+pub const DENSE_BRAILLE: Tileset = Tileset::Octants(braille_octants());
+
+// which Braille dot (as a bit offset from U+2800) lines up with each of the 2x4 regions of a cell
+const BRAILLE_DOTS: [u32; 8] = [0x01, 0x08, 0x02, 0x10, 0x04, 0x20, 0x40, 0x80];
+
+const fn braille_octants() -> [char; 256] {
+    let mut glyphs = ['\u{2800}'; 256];
+    let mut filled = 0;
+    while filled < 256 {
+        let mut dots = 0;
+        let mut region = 0;
+        while region < 8 {
+            if filled & (1 << (7 - region)) != 0 {
+                dots |= BRAILLE_DOTS[region];
+            }
+            region += 1;
+        }
+        glyphs[filled] = match char::from_u32(0x2800 + dots) {
+            Some(glyph) => glyph,
+            None => ' ',
+        };
+        filled += 1;
+    }
+    glyphs
+}